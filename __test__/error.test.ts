@@ -38,3 +38,38 @@ test('parse multiple async error', () => {
   rejects.toThrow(/\s+b\.js:[\s\S]+?Expected a semicolon or an implicit semicolon after a statement/);
 });
 
+
+test('parse unknown extension error', () => {
+  expect(() => parse("import a from 'a'", 'App.vue')).toThrow('Can not infer the language of `App.vue`');
+  expect(() => parse("import a from 'a'", 'App.vue', { fallbackLanguage: 'js' })).not.toThrow();
+});
+
+test('parse error diagnostics', () => {
+  try {
+    parse("var a number = 1", 'index.js');
+    expect.unreachable();
+  } catch (err: any) {
+    expect(err.diagnostics).toEqual([
+      {
+        filePath: 'index.js',
+        message: 'Expected a semicolon or an implicit semicolon after a statement, but found none',
+        severity: 'error',
+        labels: [
+          {
+            start: { offset: 5, charOffset: 5, line: 1, column: 5 },
+            end: { offset: 6, charOffset: 6, line: 1, column: 6 },
+          },
+        ],
+        help: 'Try insert a semicolon here',
+      },
+    ]);
+  }
+});
+
+test('parse with error recovery', () => {
+  const output = parse("import { a } from 'a';\nvar b number = 1;\nexport { a };", 'index.js', { errorRecovery: true });
+
+  expect(output.imports.map(x => x.n)).toEqual(['a']);
+  expect(output.exports.map(x => x.n)).toEqual(['a']);
+  expect(output.errors?.length).toBe(1);
+});
@@ -1,8 +1,15 @@
 import fs from 'fs';
 import { test, beforeAll, expect } from 'vitest';
-import { init, parse as parseByLexer } from 'es-module-lexer';
+import { init, parse } from 'es-module-lexer';
 import { parse as parseByOxc, parseMultiple as parseMultipleByOxc } from '..';
 
+// es-module-lexer adds the import type `t` in 1.5, derive it from `d` until we upgrade
+const parseByLexer = (sourceText: string, name: string) => {
+  const [imports, ...rest] = parse(sourceText, name);
+
+  return [imports.map(x => ({ ...x, t: x.d > -1 ? 2 : x.d === -2 ? 3 : 1 })), ...rest] as const;
+};
+
 beforeAll(async () => {
   await init;
 });
@@ -159,3 +166,290 @@ test('parse rollup.min.js', () => {
 
   expect(outputFromOxc).toEqual({ imports, exports, facade, hasModuleSyntax });
 });
+
+test('utf16 offsets', () => {
+  const sourceText = `// 😀
+import { name } from 'mod';
+export { name as other };
+`;
+
+  const outputFromOxc = parseByOxc(sourceText, 'index.js');
+  const [imports, exports, facade, hasModuleSyntax] = parseByLexer(sourceText, 'index.js');
+
+  expect(outputFromOxc).toEqual({ imports, exports, facade, hasModuleSyntax });
+  expect(sourceText.slice(outputFromOxc.imports[0].s, outputFromOxc.imports[0].e)).toBe('mod');
+});
+
+test('utf16 error positions', () => {
+  const sourceText = `import { a } from 'a';
+const s = '😀'; var b number = 1;
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { errorRecovery: true });
+
+  expect(output.errors?.[0].labels[0].start).toEqual({ offset: 44, charOffset: 43, line: 2, column: 21 });
+  expect(sourceText.slice(44, 45)).toBe(' ');
+  expect(parseByOxc(sourceText, 'index.js', { errorRecovery: true, offsetEncoding: 'utf8' }).errors?.[0].labels[0].start)
+    .toEqual({ offset: 46, charOffset: 43, line: 2, column: 23 });
+});
+
+test('locations', () => {
+  const sourceText = `import { name } from 'mod';
+export { name as other };
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { locations: true });
+
+  expect(output.imports[0].loc).toEqual({ start: { line: 1, column: 22 }, end: { line: 1, column: 25 } });
+  expect(output.imports[0].sloc).toEqual({ start: { line: 1, column: 0 }, end: { line: 1, column: 26 } });
+  expect(output.exports[0].loc).toEqual({ start: { line: 2, column: 17 }, end: { line: 2, column: 22 } });
+});
+
+test('bindings', () => {
+  const sourceText = `import a, { b as c, 'd-e' as f } from 'mod';
+import * as ns from 'ns';
+export { g as h } from 'other';
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { bindings: true });
+
+  expect(output.imports[0].bindings).toEqual([
+    { kind: 'default', local: { name: 'a', start: 7, end: 8 }, references: [] },
+    {
+      kind: 'named',
+      imported: { name: 'b', start: 12, end: 13 },
+      local: { name: 'c', start: 17, end: 18 },
+      references: [],
+    },
+    {
+      kind: 'named',
+      imported: {
+        name: 'd-e',
+        start: 21,
+        end: 24,
+        literal: { raw: 'd-e', value: 'd-e', quote: 'single' },
+      },
+      local: { name: 'f', start: 29, end: 30 },
+      references: [],
+    },
+  ]);
+  expect(output.imports[1].bindings).toEqual([
+    { kind: 'namespace', local: { name: 'ns', start: 57, end: 59 }, references: [] },
+  ]);
+  expect(output.imports[2].bindings).toEqual([
+    {
+      kind: 'named',
+      imported: { name: 'g', start: 80, end: 81 },
+      local: { name: 'h', start: 85, end: 86 },
+      references: [],
+    },
+  ]);
+  expect(parseByOxc(sourceText, 'index.js').imports[0].bindings).toBeUndefined();
+});
+
+test('binding references', () => {
+  const sourceText = `import { a, type B } from 'mod';
+import 'polyfill';
+const b: B = a();
+`;
+
+  const output = parseByOxc(sourceText, 'index.ts', { bindings: true });
+
+  expect(output.imports[0].bindings?.map(x => x.references)).toEqual([
+    [{ start: 65, end: 66 }],
+    [{ start: 61, end: 62, isType: true }],
+  ]);
+  expect(output.imports.map(x => x.isSideEffectOnly)).toEqual([undefined, true]);
+});
+
+test('side effect only imports', () => {
+  const sourceText = `import 'polyfill';
+import {} from 'a';
+import { b } from 'b';
+import('c');
+`;
+
+  const output = parseByOxc(sourceText, 'index.js');
+
+  expect(output.imports.map(x => x.isSideEffectOnly)).toEqual([true, true, undefined, undefined]);
+  expect(output.imports[0].bindings).toBeUndefined();
+});
+
+test('attributes', () => {
+  const sourceText = `import json from './a.json' with { type: 'json' };
+import('./b.json', { assert: { type: 'json' } });
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { attributes: true });
+
+  expect(output.imports[0].attributes).toEqual({
+    span: { start: 33, end: 49 },
+    keyword: 'with',
+    entries: [{ key: 'type', keySpan: { start: 35, end: 39 }, value: 'json', valueSpan: { start: 42, end: 46 } }],
+  });
+  expect(output.imports[1].attributes?.keyword).toBe('assert');
+  expect(output.imports[1].attributes?.entries.map(x => [x.key, x.value])).toEqual([['type', 'json']]);
+});
+
+test('literals', () => {
+  const sourceText = `import a from "./\\x61";
+export { a as 'b-c' };
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { literals: true });
+
+  expect(output.imports[0].literal).toEqual({ raw: './\\x61', value: './a', quote: 'double', hasEscapes: true });
+  expect(sourceText.slice(output.imports[0].s, output.imports[0].e)).toBe('./\\x61');
+  expect(output.exports[0].literal).toEqual({ raw: 'b-c', value: 'b-c', quote: 'single' });
+  expect(parseByOxc(sourceText, 'index.js').imports[0].literal).toBeUndefined();
+});
+
+test('dynamic import patterns', () => {
+  const sourceText = `import(\`./locales/\${lang}.json\`);
+import('./pages/' + name + '.js');
+`;
+
+  const output = parseByOxc(sourceText, 'index.js');
+
+  expect(output.imports[0].pattern).toEqual({
+    quasis: ['./locales/', '.json'],
+    expressions: [{ start: 20, end: 24 }],
+    glob: './locales/*.json',
+  });
+  expect(output.imports[1].pattern?.glob).toBe('./pages/*.js');
+  expect(output.imports[1].n).toBeUndefined();
+});
+
+test('import.meta.glob', () => {
+  const sourceText = `const modules = import.meta.glob(['./modules/*.ts', '!**/*.test.ts'], { eager: true, query: { raw: true } });
+console.log(import.meta.url);
+`;
+
+  const output = parseByOxc(sourceText, 'index.js');
+
+  expect(output.imports[0].glob).toEqual({
+    kind: 'glob',
+    patterns: ['./modules/*.ts', '!**/*.test.ts'],
+    options: { span: { start: 70, end: 107 }, eager: true, query: '?raw=true' },
+    span: { start: 16, end: 108 },
+  });
+  expect(output.imports[0].d).toBe(-2);
+  expect(output.imports[1].glob).toBeUndefined();
+});
+
+test('asset urls', () => {
+  const sourceText = `const logo = new URL('./logo.png', import.meta.url);
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { assetUrls: true });
+
+  expect(output.imports[0].n).toBe('./logo.png');
+  expect(output.imports[0].isAssetUrl).toBe(true);
+  expect(output.imports[0].t).toBe(3);
+  expect(sourceText.slice(output.imports[0].ss, output.imports[0].se)).toBe(
+    "new URL('./logo.png', import.meta.url)",
+  );
+  expect(parseByOxc(sourceText, 'index.js').imports[0].n).toBeUndefined();
+});
+
+test('entry points', () => {
+  const sourceText = `new Worker(new URL('./worker.js', import.meta.url), { type: 'module' });
+importScripts('./a.js');
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { entryPoints: true });
+
+  expect(output.entryPoints).toEqual([
+    {
+      kind: 'worker',
+      n: './worker.js',
+      specifierSpan: { start: 20, end: 31 },
+      isUrl: true,
+      workerType: 'module',
+      span: { start: 0, end: 71 },
+    },
+    {
+      kind: 'importScripts',
+      n: './a.js',
+      specifierSpan: { start: 88, end: 94 },
+      span: { start: 73, end: 96 },
+    },
+  ]);
+  expect(parseByOxc(sourceText, 'index.js').entryPoints).toBeUndefined();
+});
+
+test('fold constants', () => {
+  const sourceText = `const base = './icons/';
+import(base + 'x.js');
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { foldConstants: true });
+
+  expect(output.imports[0].n).toBe('./icons/x.js');
+  expect(output.imports[0].isDerived).toBe(true);
+  expect(parseByOxc(sourceText, 'index.js').imports[0].n).toBeUndefined();
+});
+
+test('export details', () => {
+  const sourceText = `const foo = 1;
+export default foo;
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { exportDetails: true });
+
+  expect(output.exports[0].local).toEqual({ name: 'foo', start: 30, end: 33 });
+  expect(output.exports[0].defaultKind).toBe('expression');
+  expect(output.exports[0].kind).toBe('const');
+  expect(output.exports[0].isType).toBeUndefined();
+  expect(parseByOxc(sourceText, 'index.js').exports[0].defaultKind).toBeUndefined();
+});
+
+test('type only', () => {
+  const sourceText = `import type { A } from 'a';
+import { type B, C } from 'b';
+export type * from 'd';
+`;
+
+  const output = parseByOxc(sourceText, 'index.ts', { bindings: true });
+
+  expect(output.imports.map(x => x.isType)).toEqual([true, undefined, true]);
+  expect(output.imports[1].bindings?.map(x => x.isType)).toEqual([true, undefined]);
+  expect(parseByOxc(sourceText, 'index.ts', { skipTypeOnly: true }).imports.map(x => x.n)).toEqual(['b']);
+});
+
+test('export origins', () => {
+  const sourceText = `import { a } from './a';
+export { a as b };
+export { c as default } from './c';
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { exportDetails: true });
+
+  expect(output.exports.map(x => x.origin)).toEqual([
+    { specifier: './a', imported: 'a', importIndex: 0 },
+    { specifier: './c', imported: 'c', importIndex: 1 },
+  ]);
+});
+
+test('mutable exports', () => {
+  const sourceText = `export let count = 0;
+export const step = 1;
+count += step;
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { exportDetails: true });
+
+  expect(output.exports.map(x => x.isMutable)).toEqual([true, undefined]);
+  expect(output.exports[0].writes).toEqual([{ start: 45, end: 50 }]);
+});
+
+test('export dependencies', () => {
+  const sourceText = `import { a } from './a';
+const helper = () => a;
+export const x = helper();
+`;
+
+  const output = parseByOxc(sourceText, 'index.js', { dependencies: true });
+
+  expect(output.exports[0].dependencies).toEqual({ imports: ['a'], declarations: ['helper'] });
+});
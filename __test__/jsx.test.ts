@@ -15,6 +15,8 @@ test('parse jsx', async () => {
         se: 71,
         d: -1,
         a: -1,
+        t: 1,
+        isType: true,
       },
       {
         n: "react",
@@ -24,6 +26,7 @@ test('parse jsx', async () => {
         se: 113,
         d: -1,
         a: -1,
+        t: 1,
       },
       {
         n: "react-fast-compare",
@@ -33,6 +36,7 @@ test('parse jsx', async () => {
         se: 159,
         d: -1,
         a: -1,
+        t: 1,
       },
       {
         n: "invariant",
@@ -42,6 +46,7 @@ test('parse jsx', async () => {
         se: 194,
         d: -1,
         a: -1,
+        t: 1,
       },
       {
         n: "./Provider",
@@ -51,6 +56,7 @@ test('parse jsx', async () => {
         se: 233,
         d: -1,
         a: -1,
+        t: 1,
       },
       {
         n: "./HelmetData",
@@ -60,6 +66,8 @@ test('parse jsx', async () => {
         se: 285,
         d: -1,
         a: -1,
+        t: 1,
+        isType: true,
       },
       {
         n: "./HelmetData",
@@ -69,6 +77,7 @@ test('parse jsx', async () => {
         se: 324,
         d: -1,
         a: -1,
+        t: 1,
       },
       {
         n: "./Dispatcher",
@@ -78,6 +87,8 @@ test('parse jsx', async () => {
         se: 383,
         d: -1,
         a: -1,
+        t: 1,
+        isType: true,
       },
       {
         n: "./Dispatcher",
@@ -87,6 +98,7 @@ test('parse jsx', async () => {
         se: 422,
         d: -1,
         a: -1,
+        t: 1,
       },
       {
         n: "./utils",
@@ -96,6 +108,7 @@ test('parse jsx', async () => {
         se: 457,
         d: -1,
         a: -1,
+        t: 1,
       },
       {
         n: "./constants",
@@ -105,6 +118,7 @@ test('parse jsx', async () => {
         se: 529,
         d: -1,
         a: -1,
+        t: 1,
       },
       {
         n: "./types",
@@ -114,6 +128,8 @@ test('parse jsx', async () => {
         se: 573,
         d: -1,
         a: -1,
+        t: 1,
+        isType: true,
       },
       {
         n: "./types",
@@ -123,6 +139,7 @@ test('parse jsx', async () => {
         se: 599,
         d: -1,
         a: -1,
+        t: 1,
       },
       {
         n: "./HelmetData",
@@ -132,6 +149,7 @@ test('parse jsx', async () => {
         se: 654,
         d: -1,
         a: -1,
+        t: 1,
       },
       {
         n: "./Provider",
@@ -141,6 +159,7 @@ test('parse jsx', async () => {
         se: 710,
         d: -1,
         a: -1,
+        t: 1,
       },
     ],
     exports: [
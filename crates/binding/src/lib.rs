@@ -6,31 +6,378 @@ extern crate napi_derive;
 use std::collections::HashMap;
 
 use es_module_lexer::{
-  parse as parse_es_module, parse_multiple as parse_multiple_es_module, ParseResult,
+  parse_multiple_with_options as parse_multiple_es_module, parse_with_options as parse_es_module,
+  AttributesKeyword, DefaultExportKind, EntryPointKind, ExportKind, ImportBindingKind, ImportKind,
+  ImportMetaGlobKind, Language, ModuleKind, OffsetEncoding, ParseResult, Quote, Severity,
 };
-use napi::{bindgen_prelude::AsyncTask, Error, Task};
+use napi::{bindgen_prelude::AsyncTask, Env, Error, Task};
+
+#[napi(object)]
+pub struct LineColumn {
+  pub line: u32,
+  pub column: u32,
+}
+
+impl From<es_module_lexer::LineColumn> for LineColumn {
+  fn from(value: es_module_lexer::LineColumn) -> Self {
+    Self {
+      line: value.line,
+      column: value.column,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct Location {
+  pub start: LineColumn,
+  pub end: LineColumn,
+}
+
+impl From<es_module_lexer::Location> for Location {
+  fn from(value: es_module_lexer::Location) -> Self {
+    Self {
+      start: value.start.into(),
+      end: value.end.into(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct QuotedString {
+  /// source text between the quotes
+  pub raw: String,
+  /// decoded value
+  pub value: String,
+  #[napi(ts_type = "'single' | 'double'")]
+  pub quote: String,
+  /// `true` if `raw` contains escape sequences
+  pub has_escapes: Option<bool>,
+}
+
+impl From<es_module_lexer::QuotedString<'_>> for QuotedString {
+  fn from(value: es_module_lexer::QuotedString) -> Self {
+    let quote = match value.quote {
+      Quote::Single => "single",
+      Quote::Double => "double",
+    };
+
+    Self {
+      raw: value.raw.into_owned(),
+      value: value.value.into_owned(),
+      quote: quote.to_string(),
+      has_escapes: value.has_escapes.then_some(true),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct Binding {
+  pub name: String,
+  pub start: u32,
+  pub end: u32,
+  /// the string literal of string names, e.g. `'a-b'` in `import { 'a-b' as c } from 'mod'`
+  pub literal: Option<QuotedString>,
+}
+
+impl From<es_module_lexer::Binding<'_>> for Binding {
+  fn from(value: es_module_lexer::Binding) -> Self {
+    Self {
+      name: value.name.into_owned(),
+      start: value.start,
+      end: value.end,
+      literal: value.literal.map(|x| x.into()),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct BindingReference {
+  pub start: u32,
+  pub end: u32,
+  /// `true` if it is in a type, which is erased from the JavaScript emit
+  pub is_type: Option<bool>,
+}
+
+impl From<es_module_lexer::BindingReference> for BindingReference {
+  fn from(value: es_module_lexer::BindingReference) -> Self {
+    Self {
+      start: value.span.start,
+      end: value.span.end,
+      is_type: value.is_type.then_some(true),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct ImportBinding {
+  #[napi(ts_type = "'default' | 'namespace' | 'named'")]
+  pub kind: String,
+  /// imported name of named bindings
+  pub imported: Option<Binding>,
+  /// local name, or the exported name for re-exports
+  pub local: Binding,
+  /// `true` if it only imports a type
+  pub is_type: Option<bool>,
+  /// references to the local name in the module, empty if it is unused
+  pub references: Vec<BindingReference>,
+}
+
+impl From<es_module_lexer::ImportBinding<'_>> for ImportBinding {
+  fn from(value: es_module_lexer::ImportBinding) -> Self {
+    let kind = match value.kind {
+      ImportBindingKind::Default => "default",
+      ImportBindingKind::Namespace => "namespace",
+      ImportBindingKind::Named => "named",
+    };
+
+    Self {
+      kind: kind.to_string(),
+      imported: value.imported.map(|x| x.into()),
+      local: value.local.into(),
+      is_type: value.is_type.then_some(true),
+      references: value.references.into_iter().map(|x| x.into()).collect(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct ExportOrigin {
+  /// specifier of the source module
+  pub specifier: String,
+  /// imported name, `'default'` for default imports, `undefined` for namespaces
+  pub imported: Option<String>,
+  /// index of the import in `imports`
+  pub import_index: u32,
+}
+
+impl From<es_module_lexer::ExportOrigin<'_>> for ExportOrigin {
+  fn from(value: es_module_lexer::ExportOrigin) -> Self {
+    Self {
+      specifier: value.specifier.into_owned(),
+      imported: value.imported.map(|x| x.into_owned()),
+      import_index: value.import_index as u32,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct ExportDependencies {
+  /// local names of the imported bindings, in the order of `imports`
+  pub imports: Vec<String>,
+  /// names of the other top-level declarations, in source order
+  pub declarations: Vec<String>,
+}
+
+impl From<es_module_lexer::ExportDependencies<'_>> for ExportDependencies {
+  fn from(value: es_module_lexer::ExportDependencies) -> Self {
+    Self {
+      imports: value.imports.into_iter().map(|x| x.into_owned()).collect(),
+      declarations: value
+        .declarations
+        .into_iter()
+        .map(|x| x.into_owned())
+        .collect(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct Span {
+  pub start: u32,
+  pub end: u32,
+}
+
+impl From<es_module_lexer::Span> for Span {
+  fn from(value: es_module_lexer::Span) -> Self {
+    Self {
+      start: value.start,
+      end: value.end,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct ImportAttribute {
+  pub key: String,
+  pub key_span: Span,
+  pub value: String,
+  pub value_span: Span,
+}
+
+impl From<es_module_lexer::ImportAttribute<'_>> for ImportAttribute {
+  fn from(value: es_module_lexer::ImportAttribute) -> Self {
+    Self {
+      key: value.key.into_owned(),
+      key_span: value.key_span.into(),
+      value: value.value.into_owned(),
+      value_span: value.value_span.into(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct ImportAttributes {
+  pub span: Span,
+  /// `undefined` for an options object of a dynamic import without a `with` or `assert` key
+  #[napi(ts_type = "'with' | 'assert'")]
+  pub keyword: Option<String>,
+  pub entries: Vec<ImportAttribute>,
+}
+
+impl From<es_module_lexer::ImportAttributes<'_>> for ImportAttributes {
+  fn from(value: es_module_lexer::ImportAttributes) -> Self {
+    let keyword = value.keyword.map(|keyword| match keyword {
+      AttributesKeyword::With => "with".to_string(),
+      AttributesKeyword::Assert => "assert".to_string(),
+    });
+
+    Self {
+      span: value.span.into(),
+      keyword,
+      entries: value.entries.into_iter().map(|x| x.into()).collect(),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct DynamicImportPattern {
+  /// static parts, one more than `expressions`
+  pub quasis: Vec<String>,
+  /// spans of the expressions between the static parts
+  pub expressions: Vec<Span>,
+  /// the static parts joined with `*`
+  pub glob: String,
+}
+
+impl From<es_module_lexer::DynamicImportPattern<'_>> for DynamicImportPattern {
+  fn from(value: es_module_lexer::DynamicImportPattern) -> Self {
+    Self {
+      quasis: value.quasis.into_iter().map(|x| x.into_owned()).collect(),
+      expressions: value.expressions.into_iter().map(|x| x.into()).collect(),
+      glob: value.glob,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct ImportMetaGlobOptions {
+  pub span: Span,
+  pub eager: Option<bool>,
+  pub import: Option<String>,
+  /// starting with `?`, object queries and `as` are serialized
+  pub query: Option<String>,
+  pub exhaustive: Option<bool>,
+  pub base: Option<String>,
+}
+
+impl From<es_module_lexer::ImportMetaGlobOptions<'_>> for ImportMetaGlobOptions {
+  fn from(value: es_module_lexer::ImportMetaGlobOptions) -> Self {
+    Self {
+      span: value.span.into(),
+      eager: value.eager,
+      import: value.import.map(|x| x.into_owned()),
+      query: value.query.map(|x| x.into_owned()),
+      exhaustive: value.exhaustive,
+      base: value.base.map(|x| x.into_owned()),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct ImportMetaGlob {
+  #[napi(ts_type = "'glob' | 'globEager'")]
+  pub kind: String,
+  /// literal patterns, negated patterns start with `!`
+  pub patterns: Vec<String>,
+  /// literal values of the options object
+  pub options: Option<ImportMetaGlobOptions>,
+  /// span of the whole call
+  pub span: Span,
+}
+
+impl From<es_module_lexer::ImportMetaGlob<'_>> for ImportMetaGlob {
+  fn from(value: es_module_lexer::ImportMetaGlob) -> Self {
+    let kind = match value.kind {
+      ImportMetaGlobKind::Glob => "glob",
+      ImportMetaGlobKind::GlobEager => "globEager",
+    };
+
+    Self {
+      kind: kind.to_string(),
+      patterns: value.patterns.into_iter().map(|x| x.into_owned()).collect(),
+      options: value.options.map(|x| x.into()),
+      span: value.span.into(),
+    }
+  }
+}
 
 #[napi(object)]
 pub struct Import {
   pub n: Option<String>,
+  /// `true` if `n` is folded from a computed specifier, if `foldConstants` is enabled
+  pub is_derived: Option<bool>,
+  /// `true` for `new URL('./logo.png', import.meta.url)`, if `assetUrls` is enabled
+  pub is_asset_url: Option<bool>,
   pub s: u32,
   pub e: u32,
   pub ss: u32,
   pub se: u32,
   pub d: i32,
   pub a: i32,
+  /// import type, `1` for static imports, `2` for dynamic imports and `3` for `import.meta`
+  pub t: u32,
+  /// the string literal of the specifier, if `literals` is enabled
+  ///
+  /// Its `raw` is `s..e`, except for dynamic imports, whose `s..e` includes the quotes.
+  pub literal: Option<QuotedString>,
+  /// the pattern of a template literal or string concatenation specifier of a dynamic import
+  pub pattern: Option<DynamicImportPattern>,
+  /// the Vite `import.meta.glob()` call of an `import.meta`
+  pub glob: Option<ImportMetaGlob>,
+  /// `true` if the whole statement only imports types
+  pub is_type: Option<bool>,
+  /// `true` for `import 'mod'` and `import {} from 'mod'`
+  pub is_side_effect_only: Option<bool>,
+  /// `true` if the statement overlaps a parse error, in error recovery mode
+  pub has_error: Option<bool>,
+  /// location of the name, if `locations` is enabled
+  pub loc: Option<Location>,
+  /// location of the statement, if `locations` is enabled
+  pub sloc: Option<Location>,
+  /// imported bindings, if `bindings` is enabled
+  pub bindings: Option<Vec<ImportBinding>>,
+  /// import attributes, if `attributes` is enabled
+  pub attributes: Option<ImportAttributes>,
 }
 
-impl From<es_module_lexer::Import> for Import {
-  fn from(value: es_module_lexer::Import) -> Self {
+impl Import {
+  fn new(value: es_module_lexer::Import, options: &OutputOptions) -> Self {
     Self {
-      n: value.n,
+      n: value.n.map(|x| x.into_owned()),
+      is_derived: value.is_derived.then_some(true),
+      is_asset_url: (value.kind == ImportKind::AssetUrl).then_some(true),
       s: value.s,
       e: value.e,
       ss: value.ss,
       se: value.se,
       d: value.d,
       a: value.a,
+      t: value.t as u32,
+      literal: value.literal.filter(|_| options.literals).map(|x| x.into()),
+      pattern: value.pattern.map(|x| x.into()),
+      glob: value.glob.map(|x| x.into()),
+      is_type: value.is_type.then_some(true),
+      is_side_effect_only: value.is_side_effect_only.then_some(true),
+      has_error: value.has_error.then_some(true),
+      loc: value.loc.map(|x| x.into()),
+      sloc: value.sloc.map(|x| x.into()),
+      bindings: options
+        .bindings
+        .then(|| value.bindings.into_iter().map(|x| x.into()).collect()),
+      attributes: value
+        .attributes
+        .filter(|_| options.attributes)
+        .map(|x| x.into()),
     }
   }
 }
@@ -43,17 +390,256 @@ pub struct Export {
   pub le: i32,
   pub n: Option<String>,
   pub ln: Option<String>,
+  /// the string literal of string names like `export { x as 'a-b' }`, if `literals` is enabled
+  pub literal: Option<QuotedString>,
+  /// `true` for a TypeScript `export = foo`, which has no name
+  pub is_export_assignment: Option<bool>,
+  /// local binding, also for `export default foo`, if `exportDetails` is enabled
+  pub local: Option<Binding>,
+  /// what an `export default` exports, if `exportDetails` is enabled
+  #[napi(
+    ts_type = "'function' | 'class' | 'interface' | 'expression' | 'anonymousFunction' | 'anonymousClass'"
+  )]
+  pub default_kind: Option<String>,
+  /// what the local binding is declared as, if `exportDetails` is enabled
+  #[napi(
+    ts_type = "'var' | 'let' | 'const' | 'function' | 'class' | 'enum' | 'interface' | 'typeAlias' | 'namespace' | 'importEquals'"
+  )]
+  pub kind: Option<String>,
+  /// `true` if it only exports a type, if `exportDetails` is enabled, otherwise unset
+  pub is_type: Option<bool>,
+  /// `true` if the local binding is written after its declaration, if `exportDetails` is enabled, otherwise unset
+  pub is_mutable: Option<bool>,
+  /// spans of the identifiers written to the local binding, if `exportDetails` is enabled
+  pub writes: Option<Vec<Span>>,
+  /// the import it re-exports, if `exportDetails` is enabled
+  pub origin: Option<ExportOrigin>,
+  /// what its declaration references, directly or through other top-level declarations,
+  /// if `dependencies` is enabled
+  pub dependencies: Option<ExportDependencies>,
+  /// `true` if the export statement overlaps a parse error, in error recovery mode
+  pub has_error: Option<bool>,
+  /// location of the name, if `locations` is enabled
+  pub loc: Option<Location>,
 }
 
-impl From<es_module_lexer::Export> for Export {
-  fn from(value: es_module_lexer::Export) -> Self {
+impl Export {
+  fn new(value: es_module_lexer::Export, options: &OutputOptions) -> Self {
+    let default_kind = value
+      .default_kind
+      .filter(|_| options.export_details)
+      .map(|kind| {
+        match kind {
+          DefaultExportKind::Function => "function",
+          DefaultExportKind::Class => "class",
+          DefaultExportKind::Interface => "interface",
+          DefaultExportKind::Expression => "expression",
+          DefaultExportKind::AnonymousFunction => "anonymousFunction",
+          DefaultExportKind::AnonymousClass => "anonymousClass",
+        }
+        .to_string()
+      });
+    let kind = value.kind.filter(|_| options.export_details).map(|kind| {
+      match kind {
+        ExportKind::Var => "var",
+        ExportKind::Let => "let",
+        ExportKind::Const => "const",
+        ExportKind::Function => "function",
+        ExportKind::Class => "class",
+        ExportKind::Enum => "enum",
+        ExportKind::Interface => "interface",
+        ExportKind::TypeAlias => "typeAlias",
+        ExportKind::Namespace => "namespace",
+        ExportKind::ImportEquals => "importEquals",
+      }
+      .to_string()
+    });
+
     Self {
       s: value.s,
       e: value.e,
       ls: value.ls,
       le: value.le,
-      n: value.n,
-      ln: value.ln,
+      n: value.n.map(|x| x.into_owned()),
+      ln: value.ln.map(|x| x.into_owned()),
+      literal: value.literal.filter(|_| options.literals).map(|x| x.into()),
+      is_export_assignment: value.is_export_assignment.then_some(true),
+      local: value
+        .local
+        .filter(|_| options.export_details)
+        .map(|x| x.into()),
+      default_kind,
+      kind,
+      is_type: (options.export_details && value.is_type).then_some(true),
+      is_mutable: (options.export_details && value.is_mutable).then_some(true),
+      writes: options
+        .export_details
+        .then(|| value.writes.into_iter().map(|x| x.into()).collect()),
+      origin: value
+        .origin
+        .filter(|_| options.export_details)
+        .map(|x| x.into()),
+      dependencies: value.dependencies.map(|x| x.into()),
+      has_error: value.has_error.then_some(true),
+      loc: value.loc.map(|x| x.into()),
+    }
+  }
+}
+
+#[derive(Clone, Default)]
+#[napi(object)]
+pub struct ParseOptions {
+  #[napi(ts_type = "'js' | 'ts' | 'dts'")]
+  pub language: Option<String>,
+  #[napi(ts_type = "'js' | 'ts' | 'dts'")]
+  pub fallback_language: Option<String>,
+  pub jsx: Option<bool>,
+  #[napi(ts_type = "'module' | 'script'")]
+  pub module_kind: Option<String>,
+  pub always_strict: Option<bool>,
+  pub error_recovery: Option<bool>,
+  /// the unit of the offsets, UTF-16 code units by default to match `String.prototype.slice`
+  #[napi(ts_type = "'utf8' | 'char' | 'utf16'")]
+  pub offset_encoding: Option<String>,
+  /// computes the line/column `loc` of every import and export
+  pub locations: Option<bool>,
+  /// adds the imported `bindings` of every import
+  pub bindings: Option<bool>,
+  /// adds the parsed `attributes` of every import
+  pub attributes: Option<bool>,
+  /// adds the `literal` of string specifiers and export names, with the raw text and quote style
+  pub literals: Option<bool>,
+  /// adds the `local` binding, `defaultKind`, `kind`, `isType`, `isMutable`, `writes` and `origin` of every export
+  pub export_details: Option<bool>,
+  /// drops the imports and exports that are types only, like the JavaScript emit of TypeScript
+  pub skip_type_only: Option<bool>,
+  /// adds the `dependencies` of every export, for tree shaking estimates
+  pub dependencies: Option<bool>,
+  /// folds dynamic import specifiers computed from strings and top-level `const` strings into `n`
+  pub fold_constants: Option<bool>,
+  /// reports `new URL('./logo.png', import.meta.url)` as an asset URL named `./logo.png` instead of an `import.meta`, except the URLs of entry points
+  pub asset_urls: Option<bool>,
+  /// adds the `entryPoints` of workers, service workers, worklets and `importScripts()`
+  pub entry_points: Option<bool>,
+}
+
+/// Options that only affect which fields end up in the `Output`.
+struct OutputOptions {
+  bindings: bool,
+  attributes: bool,
+  literals: bool,
+  export_details: bool,
+  entry_points: bool,
+  /// the unit of the offsets and columns of the diagnostics
+  offset_encoding: OffsetEncoding,
+}
+
+impl TryFrom<&ParseOptions> for OutputOptions {
+  type Error = Error;
+
+  fn try_from(value: &ParseOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      bindings: value.bindings.unwrap_or_default(),
+      attributes: value.attributes.unwrap_or_default(),
+      literals: value.literals.unwrap_or_default(),
+      export_details: value.export_details.unwrap_or_default(),
+      entry_points: value.entry_points.unwrap_or_default(),
+      offset_encoding: to_offset_encoding(&value.offset_encoding)?,
+    })
+  }
+}
+
+fn to_language(value: Option<String>, field: &str) -> Result<Option<Language>, Error> {
+  match value.as_deref() {
+    None => Ok(None),
+    Some("js") => Ok(Some(Language::JavaScript)),
+    Some("ts") => Ok(Some(Language::TypeScript)),
+    Some("dts") => Ok(Some(Language::TypeScriptDefinition)),
+    Some(other) => Err(Error::from_reason(format!(
+      "Invalid {field} `{other}`, expected one of 'js', 'ts' or 'dts'"
+    ))),
+  }
+}
+
+fn to_offset_encoding(value: &Option<String>) -> Result<OffsetEncoding, Error> {
+  match value.as_deref() {
+    None | Some("utf16") => Ok(OffsetEncoding::Utf16),
+    Some("utf8") => Ok(OffsetEncoding::Utf8),
+    Some("char") => Ok(OffsetEncoding::Char),
+    Some(other) => Err(Error::from_reason(format!(
+      "Invalid offsetEncoding `{other}`, expected one of 'utf8', 'char' or 'utf16'"
+    ))),
+  }
+}
+
+impl TryFrom<ParseOptions> for es_module_lexer::ParseOptions {
+  type Error = Error;
+
+  fn try_from(value: ParseOptions) -> Result<Self, Self::Error> {
+    let module_kind = match value.module_kind.as_deref() {
+      None => None,
+      Some("module") => Some(ModuleKind::Module),
+      Some("script") => Some(ModuleKind::Script),
+      Some(other) => {
+        return Err(Error::from_reason(format!(
+          "Invalid moduleKind `{other}`, expected one of 'module' or 'script'"
+        )))
+      }
+    };
+
+    Ok(Self {
+      language: to_language(value.language, "language")?,
+      fallback_language: to_language(value.fallback_language, "fallbackLanguage")?,
+      jsx: value.jsx,
+      module_kind,
+      always_strict: value.always_strict,
+      error_recovery: value.error_recovery.unwrap_or_default(),
+      offset_encoding: to_offset_encoding(&value.offset_encoding)?,
+      locations: value.locations.unwrap_or_default(),
+      skip_type_only: value.skip_type_only.unwrap_or_default(),
+      dependencies: value.dependencies.unwrap_or_default(),
+      fold_constants: value.fold_constants.unwrap_or_default(),
+      asset_urls: value.asset_urls.unwrap_or_default(),
+    })
+  }
+}
+
+#[napi(object)]
+pub struct EntryPoint {
+  #[napi(
+    ts_type = "'worker' | 'sharedWorker' | 'serviceWorker' | 'paintWorklet' | 'audioWorklet' | 'importScripts'"
+  )]
+  pub kind: String,
+  /// the specifier, if it is a string literal or `new URL()` of a string literal and `import.meta.url`
+  pub n: Option<String>,
+  /// span of the specifier excluding the quotes, or of the whole argument without `n`
+  pub specifier_span: Span,
+  /// `true` for `new URL('./worker.js', import.meta.url)`
+  pub is_url: Option<bool>,
+  /// the literal `type` option of workers and service workers, e.g. `'module'`
+  pub worker_type: Option<String>,
+  /// span of the whole call or `new` expression
+  pub span: Span,
+}
+
+impl From<es_module_lexer::EntryPoint<'_>> for EntryPoint {
+  fn from(value: es_module_lexer::EntryPoint) -> Self {
+    let kind = match value.kind {
+      EntryPointKind::Worker => "worker",
+      EntryPointKind::SharedWorker => "sharedWorker",
+      EntryPointKind::ServiceWorker => "serviceWorker",
+      EntryPointKind::PaintWorklet => "paintWorklet",
+      EntryPointKind::AudioWorklet => "audioWorklet",
+      EntryPointKind::ImportScripts => "importScripts",
+    };
+
+    Self {
+      kind: kind.to_string(),
+      n: value.n.map(|x| x.into_owned()),
+      specifier_span: value.specifier_span.into(),
+      is_url: value.is_url.then_some(true),
+      worker_type: value.worker_type.map(|x| x.into_owned()),
+      span: value.span.into(),
     }
   }
 }
@@ -64,54 +650,250 @@ pub struct Output {
   pub exports: Vec<Export>,
   pub facade: bool,
   pub has_module_syntax: bool,
+  /// scripts loaded as workers, service workers, worklets or by `importScripts()`, if `entryPoints` is enabled
+  pub entry_points: Option<Vec<EntryPoint>>,
+  /// parse errors, in error recovery mode
+  pub errors: Option<Vec<Diagnostic>>,
 }
 
-impl From<ParseResult> for Output {
-  fn from(value: ParseResult) -> Self {
+impl Output {
+  fn new(file_path: &str, source_text: &str, value: ParseResult, options: &OutputOptions) -> Self {
+    let errors = if value.errors.is_empty() {
+      None
+    } else {
+      Some(
+        value
+          .errors
+          .into_iter()
+          .map(|error| Diagnostic::new(file_path, source_text, error, options.offset_encoding))
+          .collect(),
+      )
+    };
+
     Self {
-      imports: value.imports.into_iter().map(|x| x.into()).collect(),
-      exports: value.exports.into_iter().map(|x| x.into()).collect(),
+      imports: value
+        .imports
+        .into_iter()
+        .map(|x| Import::new(x, options))
+        .collect(),
+      exports: value
+        .exports
+        .into_iter()
+        .map(|x| Export::new(x, options))
+        .collect(),
       facade: value.facade,
       has_module_syntax: value.has_module_syntax,
+      entry_points: options
+        .entry_points
+        .then(|| value.entry_points.into_iter().map(|x| x.into()).collect()),
+      errors,
     }
   }
 }
 
-#[napi]
-pub fn parse(source_text: String, file_path: String) -> Result<Output, Error> {
-  match parse_es_module(&source_text, &file_path) {
-    Ok(value) => Ok(value.into()),
-    Err(errors) => Err(Error::from_reason(format!("\n{}", errors.join("\n")))),
+#[napi(object)]
+pub struct Position {
+  /// offset in the selected `offsetEncoding`
+  pub offset: u32,
+  /// offset in Unicode scalar values
+  pub char_offset: u32,
+  /// 1-based line
+  pub line: u32,
+  /// 0-based column, in the selected `offsetEncoding`
+  pub column: u32,
+}
+
+impl Position {
+  fn new(
+    source_text: &str,
+    value: es_module_lexer::Position,
+    offset_encoding: OffsetEncoding,
+  ) -> Self {
+    let before = &source_text[..value.offset as usize];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let encoded_len = |text: &str| text.chars().map(|ch| offset_encoding.char_len(ch)).sum();
+
+    Self {
+      offset: encoded_len(before),
+      char_offset: value.char_offset,
+      line: value.line,
+      column: encoded_len(&before[line_start..]),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct Label {
+  pub label: Option<String>,
+  pub start: Position,
+  pub end: Position,
+}
+
+impl Label {
+  fn new(
+    source_text: &str,
+    value: es_module_lexer::Label,
+    offset_encoding: OffsetEncoding,
+  ) -> Self {
+    Self {
+      label: value.label,
+      start: Position::new(source_text, value.start, offset_encoding),
+      end: Position::new(source_text, value.end, offset_encoding),
+    }
+  }
+}
+
+#[napi(object)]
+pub struct Diagnostic {
+  pub file_path: String,
+  pub message: String,
+  #[napi(ts_type = "'error' | 'warning' | 'advice'")]
+  pub severity: String,
+  pub labels: Vec<Label>,
+  pub help: Option<String>,
+}
+
+impl Diagnostic {
+  fn new(
+    file_path: &str,
+    source_text: &str,
+    value: es_module_lexer::Diagnostic,
+    offset_encoding: OffsetEncoding,
+  ) -> Self {
+    let severity = match value.severity {
+      Severity::Error => "error",
+      Severity::Warning => "warning",
+      Severity::Advice => "advice",
+    };
+
+    Self {
+      file_path: file_path.to_string(),
+      message: value.message,
+      severity: severity.to_string(),
+      labels: value
+        .labels
+        .into_iter()
+        .map(|x| Label::new(source_text, x, offset_encoding))
+        .collect(),
+      help: value.help,
+    }
+  }
+}
+
+/// Errors of a failed parse.
+///
+/// It is thrown as a JS `Error` whose message is the rendered report,
+/// with the structured diagnostics attached as the `diagnostics` property.
+pub struct ParseError {
+  reason: String,
+  diagnostics: Vec<Diagnostic>,
+}
+
+impl From<Error> for ParseError {
+  fn from(value: Error) -> Self {
+    Self {
+      reason: value.reason,
+      diagnostics: vec![],
+    }
   }
 }
 
+impl ParseError {
+  fn into_error(self, env: Env) -> Error {
+    let mut error = match env.create_error(Error::from_reason(self.reason)) {
+      Ok(error) => error,
+      Err(err) => return err,
+    };
+
+    if let Err(err) = error.set("diagnostics", self.diagnostics) {
+      return err;
+    }
+
+    Error::from(error.into_unknown())
+  }
+}
+
+fn parse_output(
+  source_text: &str,
+  file_path: &str,
+  options: Option<ParseOptions>,
+) -> Result<Output, ParseError> {
+  let options = options.unwrap_or_default();
+  let output_options = OutputOptions::try_from(&options)?;
+
+  match parse_es_module(source_text, file_path, options.try_into()?) {
+    Ok(value) => Ok(Output::new(file_path, source_text, value, &output_options)),
+    Err(errors) => Err(ParseError {
+      reason: format!(
+        "\n{}",
+        errors
+          .iter()
+          .map(|error| error.render(source_text))
+          .collect::<Vec<String>>()
+          .join("\n")
+      ),
+      diagnostics: errors
+        .into_iter()
+        .map(|error| {
+          Diagnostic::new(
+            file_path,
+            source_text,
+            error,
+            output_options.offset_encoding,
+          )
+        })
+        .collect(),
+    }),
+  }
+}
+
+#[napi]
+pub fn parse(
+  env: Env,
+  source_text: String,
+  file_path: String,
+  options: Option<ParseOptions>,
+) -> Result<Output, Error> {
+  parse_output(&source_text, &file_path, options).map_err(|err| err.into_error(env))
+}
+
 pub struct ParseTask {
   source_text: String,
   file_path: String,
+  options: Option<ParseOptions>,
 }
 
 impl Task for ParseTask {
-  type Output = Output;
+  type Output = Result<Output, ParseError>;
   type JsValue = Output;
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
-    parse(self.source_text.clone(), self.file_path.clone())
+    Ok(parse_output(
+      &self.source_text,
+      &self.file_path,
+      self.options.clone(),
+    ))
   }
 
-  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
-    Ok(output)
+  fn resolve(&mut self, env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    output.map_err(|err| err.into_error(env))
   }
 
-  fn reject(&mut self, _env: napi::Env, err: Error) -> napi::Result<Self::JsValue> {
+  fn reject(&mut self, _env: Env, err: Error) -> napi::Result<Self::JsValue> {
     Err(err)
   }
 }
 
 #[napi(ts_return_type = "Promise<Output>")]
-pub fn parse_async(source_text: String, file_path: String) -> AsyncTask<ParseTask> {
+pub fn parse_async(
+  source_text: String,
+  file_path: String,
+  options: Option<ParseOptions>,
+) -> AsyncTask<ParseTask> {
   AsyncTask::new(ParseTask {
     source_text,
     file_path,
+    options,
   })
 }
 
@@ -122,8 +904,12 @@ pub struct ParseMultipleInput {
   pub file_path: String,
 }
 
-#[napi]
-pub fn parse_multiple(inputs: Vec<ParseMultipleInput>) -> Result<HashMap<String, Output>, Error> {
+fn parse_multiple_outputs(
+  inputs: &[ParseMultipleInput],
+  options: Option<ParseOptions>,
+) -> Result<HashMap<String, Output>, ParseError> {
+  let options = options.unwrap_or_default();
+  let output_options = OutputOptions::try_from(&options)?;
   let results = parse_multiple_es_module(
     &inputs
       .iter()
@@ -132,54 +918,85 @@ pub fn parse_multiple(inputs: Vec<ParseMultipleInput>) -> Result<HashMap<String,
         file_path: &input.file_path,
       })
       .collect::<Vec<es_module_lexer::ParseMultipleInput>>(),
+    options.try_into()?,
   );
 
+  let source_texts: HashMap<&str, &str> = inputs
+    .iter()
+    .map(|input| (input.file_path.as_str(), input.source_text.as_str()))
+    .collect();
+
   let mut outputs: HashMap<String, Output> = HashMap::new();
   let mut errors: Vec<String> = Vec::new();
+  let mut diagnostics: Vec<Diagnostic> = Vec::new();
 
   for (file_path, result) in results {
+    let source_text = source_texts[file_path.as_str()];
+
     match result {
       Ok(value) => {
-        outputs.insert(file_path, value.into());
+        let output = Output::new(&file_path, source_text, value, &output_options);
+        outputs.insert(file_path, output);
       }
       Err(errs) => {
-        errs
-          .into_iter()
-          .map(|error| format!("\n{file_path}:\n{error}"))
-          .for_each(|err| errors.push(err));
+        for error in errs {
+          errors.push(format!("\n{file_path}:\n{}", error.render(source_text)));
+          diagnostics.push(Diagnostic::new(
+            &file_path,
+            source_text,
+            error,
+            output_options.offset_encoding,
+          ));
+        }
       }
     }
   }
 
-  if errors.len() > 0 {
-    return Err(Error::from_reason(errors.join("")));
+  if !errors.is_empty() {
+    return Err(ParseError {
+      reason: errors.join(""),
+      diagnostics,
+    });
   }
 
   Ok(outputs)
 }
 
+#[napi]
+pub fn parse_multiple(
+  env: Env,
+  inputs: Vec<ParseMultipleInput>,
+  options: Option<ParseOptions>,
+) -> Result<HashMap<String, Output>, Error> {
+  parse_multiple_outputs(&inputs, options).map_err(|err| err.into_error(env))
+}
+
 pub struct ParseMultipleTask {
   inputs: Vec<ParseMultipleInput>,
+  options: Option<ParseOptions>,
 }
 
 impl Task for ParseMultipleTask {
-  type Output = HashMap<String, Output>;
+  type Output = Result<HashMap<String, Output>, ParseError>;
   type JsValue = HashMap<String, Output>;
 
   fn compute(&mut self) -> napi::Result<Self::Output> {
-    parse_multiple(self.inputs.clone())
+    Ok(parse_multiple_outputs(&self.inputs, self.options.clone()))
   }
 
-  fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
-    Ok(output)
+  fn resolve(&mut self, env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+    output.map_err(|err| err.into_error(env))
   }
 
-  fn reject(&mut self, _env: napi::Env, err: Error) -> napi::Result<Self::JsValue> {
+  fn reject(&mut self, _env: Env, err: Error) -> napi::Result<Self::JsValue> {
     Err(err)
   }
 }
 
 #[napi(ts_return_type = "Promise<Record<string, Output>>")]
-pub fn parse_multiple_async(inputs: Vec<ParseMultipleInput>) -> AsyncTask<ParseMultipleTask> {
-  AsyncTask::new(ParseMultipleTask { inputs })
+pub fn parse_multiple_async(
+  inputs: Vec<ParseMultipleInput>,
+  options: Option<ParseOptions>,
+) -> AsyncTask<ParseMultipleTask> {
+  AsyncTask::new(ParseMultipleTask { inputs, options })
 }
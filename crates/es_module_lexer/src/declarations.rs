@@ -0,0 +1,216 @@
+use oxc_ast::ast::{
+  BindingIdentifier, BindingPattern, BindingPatternKind, Declaration, ExportDefaultDeclarationKind,
+  ModifierKind, ModuleDeclaration, Program, Statement, TSModuleDeclaration,
+  TSModuleDeclarationBody, TSModuleDeclarationName, VariableDeclarationKind,
+};
+use std::collections::HashMap;
+
+use crate::{scope::collect_vars, ExportKind};
+
+/// The kind of a declaration, and whether it only declares a type.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DeclarationInfo {
+  pub kind: ExportKind,
+  pub is_type: bool,
+}
+
+impl DeclarationInfo {
+  pub fn new(decl: &Declaration) -> Self {
+    let (kind, is_type) = match decl {
+      Declaration::VariableDeclaration(var_decl) => {
+        let kind = match var_decl.kind {
+          VariableDeclarationKind::Var => ExportKind::Var,
+          VariableDeclarationKind::Let => ExportKind::Let,
+          VariableDeclarationKind::Const => ExportKind::Const,
+        };
+
+        (kind, var_decl.is_typescript_syntax())
+      }
+      // overload signatures and `declare function` are types only
+      Declaration::FunctionDeclaration(fn_decl) => {
+        (ExportKind::Function, fn_decl.is_typescript_syntax())
+      }
+      Declaration::ClassDeclaration(class_decl) => (ExportKind::Class, class_decl.is_declare()),
+      // using x = resource();
+      Declaration::UsingDeclaration(_) => (ExportKind::Const, false),
+      Declaration::TSTypeAliasDeclaration(_) => (ExportKind::TypeAlias, true),
+      Declaration::TSInterfaceDeclaration(_) => (ExportKind::Interface, true),
+      Declaration::TSEnumDeclaration(enum_decl) => (
+        ExportKind::Enum,
+        enum_decl.modifiers.contains(ModifierKind::Declare),
+      ),
+      Declaration::TSModuleDeclaration(module_decl) => (
+        ExportKind::Namespace,
+        module_decl.modifiers.contains(ModifierKind::Declare) || !is_instantiated(module_decl),
+      ),
+      Declaration::TSImportEqualsDeclaration(import_equals_decl) => (
+        ExportKind::ImportEquals,
+        import_equals_decl.import_kind.is_type(),
+      ),
+    };
+
+    Self { kind, is_type }
+  }
+}
+
+/// Whether a namespace has a runtime value, i.e. contains more than types.
+fn is_instantiated(module_decl: &TSModuleDeclaration) -> bool {
+  match &module_decl.body {
+    TSModuleDeclarationBody::TSModuleDeclaration(module_decl) => is_instantiated(module_decl),
+    TSModuleDeclarationBody::TSModuleBlock(block) => block.body.iter().any(|stmt| match stmt {
+      Statement::Declaration(decl) => !DeclarationInfo::new(decl).is_type,
+      Statement::ModuleDeclaration(module_decl) => match &**module_decl {
+        ModuleDeclaration::ExportNamedDeclaration(decl) => decl
+          .declaration
+          .as_ref()
+          .map_or(true, |decl| !DeclarationInfo::new(decl).is_type),
+        _ => true,
+      },
+      _ => true,
+    }),
+  }
+}
+
+/// Collects the top-level declarations of a program by name, exported or not,
+/// including the `var`s hoisted out of nested blocks.
+///
+/// A name with both a type and a value declaration (e.g. an interface merged with a class)
+/// is reported as the value.
+pub(crate) fn collect_declarations(program: &Program) -> HashMap<String, DeclarationInfo> {
+  let mut declarations = HashMap::new();
+  let mut declare = |name: &str, info: DeclarationInfo| {
+    declarations
+      .entry(name.to_string())
+      .and_modify(|existing: &mut DeclarationInfo| {
+        if existing.is_type && !info.is_type {
+          *existing = info;
+        }
+      })
+      .or_insert(info);
+  };
+
+  for stmt in &program.body {
+    let decl = match stmt {
+      Statement::Declaration(decl) => decl,
+      Statement::ModuleDeclaration(module_decl) => match &**module_decl {
+        ModuleDeclaration::ExportNamedDeclaration(decl) => match &decl.declaration {
+          Some(decl) => decl,
+          None => continue,
+        },
+        // export default function foo() {}
+        ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+          let (id, info) = match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(fn_decl) => (
+              &fn_decl.id,
+              DeclarationInfo {
+                kind: ExportKind::Function,
+                is_type: fn_decl.is_typescript_syntax(),
+              },
+            ),
+            ExportDefaultDeclarationKind::ClassDeclaration(class_decl) => (
+              &class_decl.id,
+              DeclarationInfo {
+                kind: ExportKind::Class,
+                is_type: class_decl.is_declare(),
+              },
+            ),
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(interface_decl) => {
+              declare(
+                &interface_decl.id.name,
+                DeclarationInfo {
+                  kind: ExportKind::Interface,
+                  is_type: true,
+                },
+              );
+              continue;
+            }
+            _ => continue,
+          };
+
+          if let Some(id) = id {
+            declare(&id.name, info);
+          }
+
+          continue;
+        }
+        _ => continue,
+      },
+      _ => continue,
+    };
+
+    let info = DeclarationInfo::new(decl);
+
+    match decl {
+      Declaration::VariableDeclaration(var_decl) => {
+        for declarator in &var_decl.declarations {
+          for_each_binding_identifier(&declarator.id, &mut |id| declare(&id.name, info));
+        }
+      }
+      Declaration::UsingDeclaration(using_decl) => {
+        for declarator in &using_decl.declarations {
+          for_each_binding_identifier(&declarator.id, &mut |id| declare(&id.name, info));
+        }
+      }
+      Declaration::FunctionDeclaration(fn_decl) => {
+        if let Some(id) = &fn_decl.id {
+          declare(&id.name, info);
+        }
+      }
+      Declaration::ClassDeclaration(class_decl) => {
+        if let Some(id) = &class_decl.id {
+          declare(&id.name, info);
+        }
+      }
+      Declaration::TSTypeAliasDeclaration(decl) => declare(&decl.id.name, info),
+      Declaration::TSInterfaceDeclaration(decl) => declare(&decl.id.name, info),
+      Declaration::TSEnumDeclaration(decl) => declare(&decl.id.name, info),
+      Declaration::TSImportEqualsDeclaration(decl) => declare(&decl.id.name, info),
+      // declare module 'mod' {} declares no name
+      Declaration::TSModuleDeclaration(decl) => {
+        if let TSModuleDeclarationName::Identifier(id) = &decl.id {
+          declare(&id.name, info);
+        }
+      }
+    }
+  }
+
+  // if (a) { var b; }
+  //              ^
+  for name in collect_vars(&program.body) {
+    declarations.entry(name).or_insert(DeclarationInfo {
+      kind: ExportKind::Var,
+      is_type: false,
+    });
+  }
+
+  declarations
+}
+
+/// Calls `f` with every identifier bound by a pattern.
+pub(crate) fn for_each_binding_identifier(
+  pattern: &BindingPattern,
+  f: &mut impl FnMut(&BindingIdentifier),
+) {
+  match &pattern.kind {
+    BindingPatternKind::BindingIdentifier(id) => f(id),
+    BindingPatternKind::ObjectPattern(pattern) => {
+      for property in &pattern.properties {
+        for_each_binding_identifier(&property.value, f);
+      }
+
+      if let Some(rest) = &pattern.rest {
+        for_each_binding_identifier(&rest.argument, f);
+      }
+    }
+    BindingPatternKind::ArrayPattern(pattern) => {
+      for element in pattern.elements.iter().flatten() {
+        for_each_binding_identifier(element, f);
+      }
+
+      if let Some(rest) = &pattern.rest {
+        for_each_binding_identifier(&rest.argument, f);
+      }
+    }
+    BindingPatternKind::AssignmentPattern(pattern) => for_each_binding_identifier(&pattern.left, f),
+  }
+}
@@ -0,0 +1,265 @@
+use oxc_ast::{
+  ast::{
+    Declaration, ExportDefaultDeclarationKind, ImportDeclarationSpecifier, JSXElementName,
+    JSXIdentifier, JSXMemberExpression, JSXMemberExpressionObject, ModuleDeclaration, Program,
+    Statement, TSModuleDeclarationName, TSModuleReference, TSType, VariableDeclarator,
+  },
+  AstKind, Visit,
+};
+use oxc_span::Span;
+use std::collections::{HashMap, HashSet};
+
+use crate::{declarations::for_each_binding_identifier, scope::Scopes};
+
+/// The top-level bindings referenced by the top-level declarations of a program.
+#[derive(Default)]
+pub(crate) struct References {
+  /// by the name of the declared binding
+  pub by_name: HashMap<String, HashSet<String>>,
+  /// by the byte start of `export default <expression>` and `export = <expression>` statements,
+  /// which declare no binding
+  pub by_statement: HashMap<u32, HashSet<String>>,
+  /// byte span of the first declaration of each name, to sort them in source order
+  pub spans: HashMap<String, Span>,
+}
+
+/// Collects which of `names` every top-level declaration references, directly.
+///
+/// References in types are ignored, as they are erased from the JavaScript emit.
+pub(crate) fn collect_references(program: &Program, names: HashSet<String>) -> References {
+  let mut collector = ReferenceCollector {
+    scopes: Scopes::new(names),
+    owners: vec![],
+    statement_start: None,
+    references: References::default(),
+  };
+
+  for stmt in &program.body {
+    match stmt {
+      Statement::Declaration(decl) => collector.visit_top_level_declaration(decl),
+      Statement::ModuleDeclaration(module_decl) => match &**module_decl {
+        ModuleDeclaration::ExportNamedDeclaration(decl) => {
+          if let Some(decl) = &decl.declaration {
+            collector.visit_top_level_declaration(decl);
+          }
+        }
+        ModuleDeclaration::ExportDefaultDeclaration(decl) => match &decl.declaration {
+          ExportDefaultDeclarationKind::FunctionDeclaration(fn_decl) => {
+            match &fn_decl.id {
+              Some(id) => collector.set_owner(&id.name, id.span),
+              None => collector.statement_start = Some(decl.span.start),
+            }
+
+            collector.visit_function(fn_decl, None);
+          }
+          ExportDefaultDeclarationKind::ClassDeclaration(class_decl) => {
+            match &class_decl.id {
+              Some(id) => collector.set_owner(&id.name, id.span),
+              None => collector.statement_start = Some(decl.span.start),
+            }
+
+            collector.visit_class(class_decl);
+          }
+          ExportDefaultDeclarationKind::Expression(expr) => {
+            collector.statement_start = Some(decl.span.start);
+            collector.visit_expression(expr);
+          }
+          ExportDefaultDeclarationKind::TSInterfaceDeclaration(_)
+          | ExportDefaultDeclarationKind::TSEnumDeclaration(_) => (),
+        },
+        // export = foo;
+        ModuleDeclaration::TSExportAssignment(decl) => {
+          collector.statement_start = Some(decl.span.start);
+          collector.visit_expression(&decl.expression);
+        }
+        _ => (),
+      },
+      _ => (),
+    }
+
+    collector.owners.clear();
+    collector.statement_start = None;
+  }
+
+  collector.references
+}
+
+struct ReferenceCollector {
+  scopes: Scopes,
+  /// names of the bindings declared by the declaration being visited
+  owners: Vec<String>,
+  /// start of the statement being visited, if it declares no binding
+  statement_start: Option<u32>,
+  references: References,
+}
+
+impl ReferenceCollector {
+  fn set_owner(&mut self, name: &str, span: Span) {
+    self.owners = vec![name.to_string()];
+    self
+      .references
+      .spans
+      .entry(name.to_string())
+      .or_insert(span);
+  }
+
+  fn visit_top_level_declaration(&mut self, decl: &Declaration) {
+    let (name, span) = match decl {
+      // const a = b, { c } = d;
+      //       ^   ^    ^    ^
+      Declaration::VariableDeclaration(var_decl) => {
+        for declarator in &var_decl.declarations {
+          self.visit_top_level_declarator(declarator);
+        }
+
+        return;
+      }
+      Declaration::UsingDeclaration(using_decl) => {
+        for declarator in &using_decl.declarations {
+          self.visit_top_level_declarator(declarator);
+        }
+
+        return;
+      }
+      Declaration::FunctionDeclaration(fn_decl) => match &fn_decl.id {
+        Some(id) => (&id.name, id.span),
+        None => return,
+      },
+      Declaration::ClassDeclaration(class_decl) => match &class_decl.id {
+        Some(id) => (&id.name, id.span),
+        None => return,
+      },
+      Declaration::TSEnumDeclaration(enum_decl) => (&enum_decl.id.name, enum_decl.id.span),
+      Declaration::TSImportEqualsDeclaration(decl) => (&decl.id.name, decl.id.span),
+      Declaration::TSModuleDeclaration(module_decl) => match &module_decl.id {
+        TSModuleDeclarationName::Identifier(id) => (&id.name, id.span),
+        TSModuleDeclarationName::StringLiteral(_) => return,
+      },
+      // types reference no runtime bindings
+      Declaration::TSTypeAliasDeclaration(_) | Declaration::TSInterfaceDeclaration(_) => return,
+    };
+
+    self.set_owner(name, span);
+    self.visit_declaration(decl);
+  }
+
+  fn visit_top_level_declarator(&mut self, declarator: &VariableDeclarator) {
+    self.owners.clear();
+
+    for_each_binding_identifier(&declarator.id, &mut |id| {
+      self.owners.push(id.name.to_string());
+      self
+        .references
+        .spans
+        .entry(id.name.to_string())
+        .or_insert(id.span);
+    });
+
+    self.visit_variable_declarator(declarator);
+  }
+
+  fn add_reference(&mut self, name: &str) {
+    if !self.scopes.is_top_level(name) {
+      return;
+    }
+
+    for owner in &self.owners {
+      self
+        .references
+        .by_name
+        .entry(owner.clone())
+        .or_default()
+        .insert(name.to_string());
+    }
+
+    if let Some(start) = self.statement_start {
+      self
+        .references
+        .by_statement
+        .entry(start)
+        .or_default()
+        .insert(name.to_string());
+    }
+  }
+}
+
+impl<'a> Visit<'a> for ReferenceCollector {
+  fn enter_node(&mut self, kind: AstKind<'a>) {
+    self.scopes.enter_node(&kind);
+
+    match kind {
+      AstKind::IdentifierReference(id) => self.add_reference(&id.name),
+      AstKind::JSXElementName(name) => {
+        if let Some(id) = get_jsx_element_reference(name) {
+          self.add_reference(&id.name);
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn leave_node(&mut self, kind: AstKind<'a>) {
+    self.scopes.leave_node(&kind);
+  }
+
+  fn visit_ts_type(&mut self, _ty: &TSType<'a>) {}
+}
+
+/// Gets the identifier a JSX element name references, `None` for intrinsic elements like `<div />`.
+///
+/// ```jsx
+/// <Foo />, <Foo.Bar />
+///  ^^^      ^^^
+/// ```
+pub(crate) fn get_jsx_element_reference<'a>(name: &'a JSXElementName) -> Option<&'a JSXIdentifier> {
+  match name {
+    JSXElementName::Identifier(id) => {
+      (!id.name.starts_with(|ch: char| ch.is_ascii_lowercase())).then_some(id)
+    }
+    JSXElementName::MemberExpression(expr) => {
+      let mut expr: &JSXMemberExpression = expr;
+
+      loop {
+        match &expr.object {
+          JSXMemberExpressionObject::Identifier(id) => return Some(id),
+          JSXMemberExpressionObject::MemberExpression(object) => expr = object,
+        }
+      }
+    }
+    JSXElementName::NamespacedName(_) => None,
+  }
+}
+
+/// Gets the local names of the bindings imported by `import` declarations and `import x = require()`.
+pub(crate) fn collect_imported_names(program: &Program) -> HashSet<String> {
+  let mut names = HashSet::new();
+
+  for stmt in &program.body {
+    let Statement::ModuleDeclaration(module_decl) = stmt else {
+      // import fs = require('fs');
+      //        ^^
+      if let Statement::Declaration(Declaration::TSImportEqualsDeclaration(decl)) = stmt {
+        if let TSModuleReference::ExternalModuleReference(_) = &*decl.module_reference {
+          names.insert(decl.id.name.to_string());
+        }
+      }
+
+      continue;
+    };
+    let ModuleDeclaration::ImportDeclaration(decl) = &**module_decl else {
+      continue;
+    };
+
+    for specifier in decl.specifiers.iter().flatten() {
+      let local = match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => &specifier.local,
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => &specifier.local,
+      };
+
+      names.insert(local.name.to_string());
+    }
+  }
+
+  names
+}
@@ -0,0 +1,148 @@
+use oxc_diagnostics::miette::{self, LabeledSpan, Report};
+use std::fmt;
+
+/// The severity of a `Diagnostic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+  Error,
+  Warning,
+  Advice,
+}
+
+/// A position in the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+  /// UTF-8 byte offset
+  pub offset: u32,
+  /// offset in Unicode scalar values
+  pub char_offset: u32,
+  /// 1-based line
+  pub line: u32,
+  /// 0-based column, in Unicode scalar values
+  pub column: u32,
+}
+
+impl Position {
+  fn new(source_text: &str, offset: usize) -> Self {
+    let mut offset = offset.min(source_text.len());
+
+    while !source_text.is_char_boundary(offset) {
+      offset -= 1;
+    }
+
+    let before = &source_text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+    Self {
+      offset: offset as u32,
+      char_offset: before.chars().count() as u32,
+      line: before.matches('\n').count() as u32 + 1,
+      column: before[line_start..].chars().count() as u32,
+    }
+  }
+}
+
+/// A labeled span of the source text that a `Diagnostic` points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+  pub label: Option<String>,
+  pub start: Position,
+  pub end: Position,
+}
+
+/// A diagnostic reported while parsing a source text.
+///
+/// `Display` only prints the message, use `render` for a report with the source code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+  pub message: String,
+  pub severity: Severity,
+  pub labels: Vec<Label>,
+  pub help: Option<String>,
+}
+
+impl Diagnostic {
+  /// Creates an error without labels, e.g. for problems with the file path.
+  pub(crate) fn error(message: String) -> Self {
+    Self {
+      message,
+      severity: Severity::Error,
+      labels: vec![],
+      help: None,
+    }
+  }
+
+  pub(crate) fn from_oxc(error: &oxc_diagnostics::Error, source_text: &str) -> Self {
+    let severity = match error.severity() {
+      Some(miette::Severity::Warning) => Severity::Warning,
+      Some(miette::Severity::Advice) => Severity::Advice,
+      Some(miette::Severity::Error) | None => Severity::Error,
+    };
+
+    let labels = error
+      .labels()
+      .map(|labels| {
+        labels
+          .map(|label| Label {
+            label: label.label().map(|x| x.to_string()),
+            start: Position::new(source_text, label.offset()),
+            end: Position::new(source_text, label.offset() + label.len()),
+          })
+          .collect()
+      })
+      .unwrap_or_default();
+
+    Self {
+      message: error.to_string(),
+      severity,
+      labels,
+      help: error.help().map(|help| help.to_string()),
+    }
+  }
+
+  /// Renders the diagnostic as a graphical report with the related source code.
+  pub fn render(&self, source_text: &str) -> String {
+    let report = Report::new(self.clone()).with_source_code(source_text.to_string());
+
+    format!("{report:?}")
+  }
+}
+
+impl fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.message)
+  }
+}
+
+impl std::error::Error for Diagnostic {}
+
+impl miette::Diagnostic for Diagnostic {
+  fn severity(&self) -> Option<miette::Severity> {
+    Some(match self.severity {
+      Severity::Error => miette::Severity::Error,
+      Severity::Warning => miette::Severity::Warning,
+      Severity::Advice => miette::Severity::Advice,
+    })
+  }
+
+  fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+    self
+      .help
+      .as_ref()
+      .map(|help| Box::new(help) as Box<dyn fmt::Display>)
+  }
+
+  fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+    if self.labels.is_empty() {
+      return None;
+    }
+
+    Some(Box::new(self.labels.iter().map(|label| {
+      LabeledSpan::new(
+        label.label.clone(),
+        label.start.offset as usize,
+        (label.end.offset - label.start.offset) as usize,
+      )
+    })))
+  }
+}
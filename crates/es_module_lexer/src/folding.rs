@@ -0,0 +1,139 @@
+use oxc_ast::{
+  ast::{
+    BindingPatternKind, Declaration, Expression, ModuleDeclaration, Program, Statement,
+    VariableDeclaration, VariableDeclarationKind,
+  },
+  AstKind, Visit,
+};
+use oxc_syntax::operator::BinaryOperator;
+use std::collections::HashMap;
+
+use crate::scope::Scopes;
+
+/// How many `const`s a specifier is folded through at most, which also stops `const a = b, b = a`.
+const MAX_DEPTH: u32 = 16;
+
+/// Folds the specifiers of dynamic imports that are computed from strings and top-level `const` strings,
+/// e.g. `import(base + 'x.js')` with `const base = './icons/'`, by the byte start of the import expression.
+///
+/// Only string literals, template literals, `+` and references to top-level `const`s are folded,
+/// a reference to a `const` that is shadowed at the import is not.
+pub(crate) fn fold_dynamic_imports(program: &Program) -> HashMap<u32, String> {
+  let mut constants = HashMap::new();
+
+  for stmt in &program.body {
+    let var_decl = match stmt {
+      Statement::Declaration(Declaration::VariableDeclaration(var_decl)) => var_decl,
+      Statement::ModuleDeclaration(module_decl) => match &**module_decl {
+        ModuleDeclaration::ExportNamedDeclaration(decl) => match &decl.declaration {
+          Some(Declaration::VariableDeclaration(var_decl)) => var_decl,
+          _ => continue,
+        },
+        _ => continue,
+      },
+      _ => continue,
+    };
+
+    add_constants(&mut constants, var_decl);
+  }
+
+  let mut folder = ImportFolder {
+    scopes: Scopes::new(constants.keys().map(|name| name.to_string()).collect()),
+    constants,
+    folded: HashMap::new(),
+  };
+
+  folder.visit_program(program);
+  folder.folded
+}
+
+/// Adds the `const x = <expression>` declarators of a declaration.
+fn add_constants<'a, 'b>(
+  constants: &mut HashMap<&'b str, &'b Expression<'a>>,
+  var_decl: &'b VariableDeclaration<'a>,
+) {
+  if var_decl.kind != VariableDeclarationKind::Const {
+    return;
+  }
+
+  for declarator in &var_decl.declarations {
+    if let (BindingPatternKind::BindingIdentifier(id), Some(init)) =
+      (&declarator.id.kind, &declarator.init)
+    {
+      constants.insert(id.name.as_str(), init);
+    }
+  }
+}
+
+struct ImportFolder<'a, 'b> {
+  scopes: Scopes,
+  /// initializers of the top-level `const`s by name
+  constants: HashMap<&'b str, &'b Expression<'a>>,
+  folded: HashMap<u32, String>,
+}
+
+impl<'a, 'b> ImportFolder<'a, 'b> {
+  /// Folds an expression into a string.
+  ///
+  /// `depth` is `0` at the import, where the references are resolved with the nested scopes,
+  /// and increases inside the initializers of the top-level `const`s.
+  fn fold(&self, expr: &Expression, depth: u32) -> Option<String> {
+    match expr.get_inner_expression() {
+      Expression::StringLiteral(literal) => Some(literal.value.to_string()),
+      // `${base}/a.js`
+      Expression::TemplateLiteral(literal) => {
+        let mut value = String::new();
+
+        for (index, quasi) in literal.quasis.iter().enumerate() {
+          if let Some(expr) = index
+            .checked_sub(1)
+            .and_then(|i| literal.expressions.get(i))
+          {
+            value.push_str(&self.fold(expr, depth)?);
+          }
+
+          value.push_str(quasi.value.cooked.as_ref()?);
+        }
+
+        Some(value)
+      }
+      // base + 'a.js'
+      Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+        let mut value = self.fold(&expr.left, depth)?;
+
+        value.push_str(&self.fold(&expr.right, depth)?);
+
+        Some(value)
+      }
+      Expression::Identifier(id) => {
+        if depth >= MAX_DEPTH || (depth == 0 && !self.scopes.is_top_level(&id.name)) {
+          return None;
+        }
+
+        self.fold(self.constants.get(id.name.as_str())?, depth + 1)
+      }
+      _ => None,
+    }
+  }
+}
+
+impl<'a, 'b> Visit<'a> for ImportFolder<'a, 'b> {
+  fn enter_node(&mut self, kind: AstKind<'a>) {
+    self.scopes.enter_node(&kind);
+
+    if let AstKind::ImportExpression(expr) = kind {
+      // string literals are the name of the import already
+      if let Expression::StringLiteral(_) = expr.source {
+        return;
+      }
+
+      if let Some(value) = self.fold(&expr.source, 0) {
+        self.folded.insert(expr.span.start, value);
+      }
+    }
+  }
+
+  fn leave_node(&mut self, kind: AstKind<'a>) {
+    self.scopes.leave_node(&kind);
+  }
+}
@@ -1,26 +1,45 @@
 mod constants;
+mod declarations;
+mod dependencies;
+mod error;
+mod folding;
+mod mutations;
+mod options;
+mod recovery;
+mod scope;
+mod usages;
 mod visitor;
 
+pub use error::{Diagnostic, Label, Position, Severity};
+pub use options::{Language, ModuleKind, OffsetEncoding, ParseOptions};
 use oxc_allocator::Allocator;
 use oxc_ast::Visit;
 use oxc_parser::Parser;
-use oxc_span::SourceType;
 use rayon::prelude::*;
-use std::{collections::HashMap, path::Path};
+use recovery::recover_source_text;
+use std::collections::HashMap;
 use visitor::Visitor;
-pub use visitor::{Export, Import, ParseResult};
+pub use visitor::{
+  AttributesKeyword, Binding, BindingReference, DefaultExportKind, DynamicImportPattern,
+  EntryPoint, EntryPointKind, Export, ExportDependencies, ExportKind, ExportOrigin, Import,
+  ImportAttribute, ImportAttributes, ImportBinding, ImportBindingKind, ImportKind, ImportMetaGlob,
+  ImportMetaGlobKind, ImportMetaGlobOptions, ImportType, LineColumn, Location, ParseResult, Quote,
+  QuotedString, Span,
+};
 
 /// Parses a source text and returns `ParseResult` or an `Err` with parsing errors.
 ///
 /// The `parse` function takes a source text and a file path as input. It uses the `Parser` from the `Allocator`
-/// to parse the source text. The file path is used to determine the `SourceType`.
+/// to parse the source text. The file path is used to determine the `SourceType`. Query and hash suffixes of
+/// bundler module ids (e.g. `/src/App.vue?vue&type=script&lang.ts`) are taken into account. If the `SourceType`
+/// can not be determined, an `Err` describing the file path is returned.
 ///
-/// Once parsed, if there are any errors during parsing, they are collected and formatted with their source
-/// codes and returned as a vector of strings in an `Err`. The errors are related to the syntax and semantics
-/// of the source text.
+/// Once parsed, if there are any errors during parsing, they are collected and returned as a vector of
+/// `Diagnostic`s in an `Err`. The errors are related to the syntax and semantics of the source text.
+/// Use `Diagnostic::render` to format them with their source codes.
 ///
 /// If no errors exist, a `Visitor` is used to visit the parsed program and return a `ParseResult` wrapped
-/// in an `Ok`.
+/// in an `Ok`. See `ParseOptions::error_recovery` to get the `ParseResult` of a program with errors.
 ///
 /// # Arguments
 ///
@@ -29,10 +48,11 @@ pub use visitor::{Export, Import, ParseResult};
 ///
 /// # Returns
 ///
-/// Returns a `Result<ParseResult, Vec<String>>`.
+/// Returns a `Result<ParseResult, Vec<Diagnostic>>`.
 ///
-/// In the case of successful parsing, it yields `Ok(ParseResult)`.
-/// If there are parsing errors, it yields `Err`, with a vector of strings, each indicating a parsing error.
+/// In the case of successful parsing, it yields `Ok(ParseResult)`. The names in the `ParseResult` borrow from
+/// the source text where possible, use `ParseResult::into_owned` to detach it from the source text.
+/// If there are parsing errors, it yields `Err`, with a vector of `Diagnostic`s, each indicating a parsing error.
 ///
 /// # Example
 ///
@@ -44,25 +64,69 @@ pub use visitor::{Export, Import, ParseResult};
 ///
 /// let result = parse(source_text, file_path);
 /// ```
-pub fn parse(source_text: &str, file_path: &str) -> Result<ParseResult, Vec<String>> {
+pub fn parse<'a>(
+  source_text: &'a str,
+  file_path: &str,
+) -> Result<ParseResult<'a>, Vec<Diagnostic>> {
+  parse_with_options(source_text, file_path, ParseOptions::default())
+}
+
+/// Parses a source text like `parse`, with `ParseOptions` overriding what is inferred from the file path.
+///
+/// This is useful for source texts that have no real path (editor buffers, code generated by loaders),
+/// or whose extension does not match their content (e.g. a `.js` file containing TypeScript).
+///
+/// # Example
+///
+/// ```
+/// use es_module_lexer::*;
+///
+/// let source_text = "import type { Foo } from 'mod'";
+/// let options = ParseOptions {
+///   language: Some(Language::TypeScript),
+///   ..Default::default()
+/// };
+///
+/// let result = parse_with_options(source_text, "virtual-module", options);
+/// ```
+pub fn parse_with_options<'a>(
+  source_text: &'a str,
+  file_path: &str,
+  options: ParseOptions,
+) -> Result<ParseResult<'a>, Vec<Diagnostic>> {
+  let recovered_text;
   let allocator = Allocator::default();
-  let source_type = SourceType::from_path(Path::new(file_path)).unwrap();
-  let res = Parser::new(&allocator, source_text, source_type).parse();
+  let source_type = options
+    .source_type(file_path)
+    .map_err(|error| vec![Diagnostic::error(error)])?;
+  let mut res = Parser::new(&allocator, source_text, source_type).parse();
 
-  if res.errors.len() > 0 {
-    let source = source_text.to_string();
-    let errors: Vec<String> = res
-      .errors
-      .into_iter()
-      .map(|error| error.with_source_code(source.clone()))
-      .map(|error| format!("{error:?}"))
-      .collect();
+  let mut errors: Vec<Diagnostic> = res
+    .errors
+    .iter()
+    .map(|error| Diagnostic::from_oxc(error, source_text))
+    .collect();
 
+  if !errors.is_empty() && !options.error_recovery {
     return Err(errors);
   }
 
-  let mut visitor = Visitor::new(source_text);
+  if res.panicked {
+    if let Some(text) = recover_source_text(source_text, source_type, &mut errors) {
+      recovered_text = text;
+      res = Parser::new(&allocator, &recovered_text, source_type).parse();
+    }
+  }
+
+  let mut visitor = Visitor::new(source_text, &options);
+  visitor.error_spans = errors
+    .iter()
+    .flat_map(|error| &error.labels)
+    .map(|label| oxc_span::Span::new(label.start.offset, label.end.offset))
+    .collect();
+  visitor.is_definition = source_type.is_typescript_definition();
   visitor.visit_program(&res.program);
+  visitor.result.errors = errors;
 
   Ok(visitor.result)
 }
@@ -80,7 +144,7 @@ pub struct ParseMultipleInput<'a> {
 ///
 /// The resulting `HashMap` uses the `file_path` as key and the result of the `parse` function as value.
 /// If the `parse` function successfully parses the `source_text`, it yields `Ok(ParseResult)`.
-/// Otherwise, it yields `Err` with a vector of strings indicating errors encountered during the parsing of `source_text`.
+/// Otherwise, it yields `Err` with a vector of `Diagnostic`s indicating errors encountered during the parsing of `source_text`.
 ///
 /// # Arguments
 ///
@@ -88,9 +152,9 @@ pub struct ParseMultipleInput<'a> {
 ///
 /// # Returns
 ///
-/// A `HashMap<String, Result<ParseResult, Vec<String>>>` where:
+/// A `HashMap<String, Result<ParseResult, Vec<Diagnostic>>>` where:
 /// - The keys of the `HashMap` are the `file_path` strings.
-/// - The values of the `HashMap` are a `Result` that, if parsing was successful, yields a `ParseResult`, otherwise a vector of strings indicating parsing errors.
+/// - The values of the `HashMap` are a `Result` that, if parsing was successful, yields a `ParseResult`, otherwise a vector of `Diagnostic`s indicating parsing errors.
 ///
 /// # Example
 ///
@@ -110,15 +174,23 @@ pub struct ParseMultipleInput<'a> {
 ///
 /// let result = parse_multiple(&inputs);
 /// ```
-pub fn parse_multiple(
-  inputs: &[ParseMultipleInput],
-) -> HashMap<String, Result<ParseResult, Vec<String>>> {
+pub fn parse_multiple<'a>(
+  inputs: &[ParseMultipleInput<'a>],
+) -> HashMap<String, Result<ParseResult<'a>, Vec<Diagnostic>>> {
+  parse_multiple_with_options(inputs, ParseOptions::default())
+}
+
+/// Parses multiple source texts in parallel like `parse_multiple`, applying the same `ParseOptions` to each of them.
+pub fn parse_multiple_with_options<'a>(
+  inputs: &[ParseMultipleInput<'a>],
+  options: ParseOptions,
+) -> HashMap<String, Result<ParseResult<'a>, Vec<Diagnostic>>> {
   inputs
     .par_iter()
     .map(|input| {
       (
         input.file_path.to_string(),
-        parse(input.source_text, input.file_path),
+        parse_with_options(input.source_text, input.file_path, options.clone()),
       )
     })
     .collect()
@@ -0,0 +1,74 @@
+use oxc_ast::{
+  ast::{AssignmentTargetPropertyIdentifier, Program, SimpleAssignmentTarget},
+  AstKind, Visit,
+};
+use oxc_span::Span;
+use std::collections::HashMap;
+
+use crate::{declarations::DeclarationInfo, scope::Scopes};
+
+/// Collects the writes to top-level bindings, e.g. `count++` or `x = 2`, by name.
+///
+/// Writes to a binding that is shadowed by a parameter or an inner declaration are not collected.
+/// The spans are the byte spans of the written identifiers.
+pub(crate) fn collect_mutations(
+  program: &Program,
+  declarations: &HashMap<String, DeclarationInfo>,
+) -> HashMap<String, Vec<Span>> {
+  let mut collector = MutationCollector {
+    scopes: Scopes::new(declarations.keys().cloned().collect()),
+    mutations: HashMap::new(),
+  };
+
+  collector.visit_program(program);
+  collector.mutations
+}
+
+struct MutationCollector {
+  scopes: Scopes,
+  mutations: HashMap<String, Vec<Span>>,
+}
+
+impl MutationCollector {
+  fn add_write(&mut self, name: &str, span: Span) {
+    if self.scopes.is_top_level(name) {
+      self
+        .mutations
+        .entry(name.to_string())
+        .or_default()
+        .push(span);
+    }
+  }
+}
+
+impl<'a> Visit<'a> for MutationCollector {
+  fn enter_node(&mut self, kind: AstKind<'a>) {
+    self.scopes.enter_node(&kind);
+
+    // x = 1, x += 1, x++, [x] = arr, for (x of xs)
+    // ^      ^       ^     ^              ^
+    if let AstKind::SimpleAssignmentTarget(SimpleAssignmentTarget::AssignmentTargetIdentifier(id)) =
+      kind
+    {
+      self.add_write(&id.name, id.span);
+    }
+  }
+
+  fn leave_node(&mut self, kind: AstKind<'a>) {
+    self.scopes.leave_node(&kind);
+  }
+
+  // ({ x } = obj)
+  //    ^
+  fn visit_assignment_target_property_identifier(
+    &mut self,
+    ident: &AssignmentTargetPropertyIdentifier<'a>,
+  ) {
+    self.add_write(&ident.binding.name, ident.binding.span);
+    self.visit_identifier_reference(&ident.binding);
+
+    if let Some(expr) = &ident.init {
+      self.visit_expression(expr);
+    }
+  }
+}
@@ -0,0 +1,182 @@
+use oxc_span::SourceType;
+use std::path::Path;
+
+/// The language of the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+  /// `.js` / `.mjs` / `.cjs` / `.jsx`
+  JavaScript,
+  /// `.ts` / `.mts` / `.cts` / `.tsx`
+  TypeScript,
+  /// `.d.ts` / `.d.mts` / `.d.cts`
+  TypeScriptDefinition,
+}
+
+/// The goal symbol the source text is parsed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleKind {
+  Module,
+  Script,
+}
+
+/// The unit of the offsets (`s`, `e`, `ss`, `se`, `d`, `a`, `ls`, `le`) in `ParseResult`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OffsetEncoding {
+  /// UTF-8 bytes, for slicing the `&str` in Rust
+  Utf8,
+  /// Unicode scalar values, i.e. `char`s
+  #[default]
+  Char,
+  /// UTF-16 code units, for `String.prototype.slice` in JavaScript
+  Utf16,
+}
+
+impl OffsetEncoding {
+  /// The length of a `char` in this encoding.
+  pub fn char_len(self, ch: char) -> u32 {
+    match self {
+      OffsetEncoding::Utf8 => ch.len_utf8() as u32,
+      OffsetEncoding::Char => 1,
+      OffsetEncoding::Utf16 => ch.len_utf16() as u32,
+    }
+  }
+}
+
+/// Options for `parse_with_options` and `parse_multiple_with_options`.
+///
+/// Every field is optional. A `None` field falls back to what is inferred from the file path,
+/// so `ParseOptions::default()` behaves exactly like `parse`.
+///
+/// # Example
+///
+/// ```
+/// use es_module_lexer::*;
+///
+/// // a `.js` file that is actually TypeScript with JSX
+/// let options = ParseOptions {
+///   language: Some(Language::TypeScript),
+///   jsx: Some(true),
+///   ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+  /// Overrides the language inferred from the file extension.
+  pub language: Option<Language>,
+  /// The language used when it can not be inferred from the file path,
+  /// e.g. for `.vue` / `.svelte` files, files without extension or `\0virtual:foo` module ids.
+  ///
+  /// If it is `None`, parsing such a file fails with an error.
+  pub fallback_language: Option<Language>,
+  /// Overrides whether JSX is enabled.
+  ///
+  /// By default JSX is enabled for `.js` / `.mjs` / `.cjs` / `.jsx` / `.tsx`. If the language is not
+  /// the one inferred from the path but set by `language` or `fallback_language`, JSX is enabled for JavaScript only.
+  pub jsx: Option<bool>,
+  /// Overrides the goal symbol, `ModuleKind::Module` by default.
+  pub module_kind: Option<ModuleKind>,
+  /// Parses the source text in strict mode even if it is a script.
+  pub always_strict: Option<bool>,
+  /// Returns the `ParseResult` of the recovered program instead of an `Err` when there are parse errors.
+  ///
+  /// The errors are reported in `ParseResult::errors`, and the imports and exports
+  /// of the statements that overlap them are flagged with `has_error`.
+  pub error_recovery: bool,
+  /// The unit of the offsets in `ParseResult`, `OffsetEncoding::Char` by default.
+  pub offset_encoding: OffsetEncoding,
+  /// Computes the line/column `loc` of every import and export.
+  pub locations: bool,
+  /// Drops the imports and exports that are types only (see `Import::is_type` and `Export::is_type`),
+  /// so that the `ParseResult` matches what the JavaScript emit of TypeScript contains.
+  pub skip_type_only: bool,
+  /// Computes the `dependencies` of every export, see `Export::dependencies`.
+  pub dependencies: bool,
+  /// Folds the specifiers of dynamic imports that are computed from strings and top-level `const` strings
+  /// into their name `n`, e.g. `import(base + 'x.js')` with `const base = './icons/'`, see `Import::is_derived`.
+  pub fold_constants: bool,
+  /// Reports `new URL('./logo.png', import.meta.url)` as an `ImportKind::AssetUrl` import named `./logo.png`,
+  /// instead of an `ImportKind::ImportMeta` import of its `import.meta`.
+  ///
+  /// The URLs of entry points like `new Worker(new URL('./worker.js', import.meta.url))` stay `import.meta` imports,
+  /// as they are reported in `ParseResult::entry_points`.
+  pub asset_urls: bool,
+}
+
+impl ParseOptions {
+  /// Resolves the `SourceType` for `file_path`, with the overrides of these options applied.
+  ///
+  /// Returns an `Err` if the language can neither be inferred from `file_path` nor is provided by the options.
+  pub(crate) fn source_type(&self, file_path: &str) -> Result<SourceType, String> {
+    let inferred = infer_source_type(file_path);
+    let inferred_language = inferred.map(|inferred| {
+      if inferred.is_typescript_definition() {
+        Language::TypeScriptDefinition
+      } else if inferred.is_typescript() {
+        Language::TypeScript
+      } else {
+        Language::JavaScript
+      }
+    });
+
+    let language = match (self.language, inferred_language) {
+      (Some(language), _) | (None, Some(language)) => language,
+      (None, None) => self.fallback_language.ok_or_else(|| {
+        format!(
+          "Can not infer the language of `{file_path}`, expected a .js, .mjs, .cjs, .jsx, .ts, .mts, .cts or .tsx extension, or a `lang.xx` query. Set `language` or `fallback_language` in `ParseOptions` to parse it anyway."
+        )
+      })?,
+    };
+
+    let inferred = inferred.unwrap_or_default();
+    // JSX is inferred from the path only if the language is, e.g. a `.js` file parsed as TypeScript
+    // has no JSX, which would break `<T>x` casts
+    let jsx = self.jsx.unwrap_or(if inferred_language == Some(language) {
+      inferred.is_jsx()
+    } else {
+      language == Language::JavaScript
+    });
+    let module_kind = self.module_kind.unwrap_or(ModuleKind::Module);
+    let always_strict = self.always_strict.unwrap_or(inferred.always_strict());
+
+    Ok(
+      SourceType::default()
+        .with_typescript(language == Language::TypeScript)
+        .with_typescript_definition(language == Language::TypeScriptDefinition)
+        .with_jsx(jsx)
+        .with_module(module_kind == ModuleKind::Module)
+        .with_always_strict(always_strict),
+    )
+  }
+}
+
+/// Infers the `SourceType` from a file path or a bundler module id.
+///
+/// - `/src/foo.ts?raw#hash`: the query and hash are stripped, so `.ts` is used
+/// - `/src/App.vue?vue&type=script&setup=true&lang.ts`: the `lang.ts` (or `lang=ts`) query wins
+/// - `\0virtual:foo` / `/src/App.vue`: `None`
+fn infer_source_type(file_path: &str) -> Option<SourceType> {
+  let (path, query) = match file_path.split_once('?') {
+    Some((path, query)) => (path, Some(query)),
+    None => (file_path, None),
+  };
+  let path = path.split_once('#').map_or(path, |(path, _)| path);
+  let query = query.map(|query| query.split_once('#').map_or(query, |(query, _)| query));
+
+  // /src/App.vue?vue&type=script&setup=true&lang.ts
+  //                                         ^^^^^^^
+  let lang = query.and_then(|query| {
+    query.split('&').find_map(|param| {
+      param
+        .strip_prefix("lang.")
+        .or_else(|| param.strip_prefix("lang="))
+    })
+  });
+
+  if let Some(lang) = lang {
+    if let Ok(source_type) = SourceType::from_path(format!("file.{lang}")) {
+      return Some(source_type);
+    }
+  }
+
+  SourceType::from_path(Path::new(path)).ok()
+}
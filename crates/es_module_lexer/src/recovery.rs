@@ -0,0 +1,103 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+use crate::Diagnostic;
+
+/// The max number of lines blanked out before giving up.
+const MAX_RECOVERY_ATTEMPTS: usize = 32;
+
+/// Makes a source text parsable after the parser panicked on an unrecoverable error.
+///
+/// oxc returns an empty program for most syntax errors, so the lines the errors point at are
+/// blanked out with spaces (keeping all byte offsets intact) until the rest of the source text parses.
+/// The errors found along the way, including the recoverable ones of the final parse, are appended to `errors`.
+/// Their offsets are those of `source_text`, as blanking out keeps all byte offsets intact.
+///
+/// Returns `None` if the source text can not be recovered.
+pub(crate) fn recover_source_text(
+  source_text: &str,
+  source_type: SourceType,
+  errors: &mut Vec<Diagnostic>,
+) -> Option<String> {
+  let mut bytes = source_text.as_bytes().to_vec();
+  let mut offset = errors.last()?.labels.first()?.start.offset as usize;
+
+  for _ in 0..MAX_RECOVERY_ATTEMPTS {
+    if !blank_line(&mut bytes, offset) {
+      return None;
+    }
+
+    // whole lines are replaced with ASCII spaces, so it is still valid UTF-8
+    let text = String::from_utf8(bytes).ok()?;
+    let (panicked, mut new_errors) = {
+      let allocator = Allocator::default();
+      let res = Parser::new(&allocator, &text, source_type).parse();
+      let new_errors = res
+        .errors
+        .iter()
+        .map(|error| Diagnostic::from_oxc(error, source_text))
+        .collect::<Vec<_>>();
+
+      (res.panicked, new_errors)
+    };
+
+    if !panicked {
+      for error in new_errors {
+        if !errors.contains(&error) {
+          errors.push(error);
+        }
+      }
+
+      return Some(text);
+    }
+
+    let error = new_errors.pop()?;
+    offset = error.labels.first()?.start.offset as usize;
+
+    if !errors.contains(&error) {
+      errors.push(error);
+    }
+
+    bytes = text.into_bytes();
+  }
+
+  None
+}
+
+/// Replaces the line at `offset` with spaces.
+///
+/// If that line is already blank, e.g. for an error at the end of the source text,
+/// the closest non-blank line before it is used instead.
+///
+/// Returns `false` if there is nothing left to blank out.
+fn blank_line(bytes: &mut [u8], offset: usize) -> bool {
+  let mut end = offset.min(bytes.len());
+
+  loop {
+    let start = bytes[..end]
+      .iter()
+      .rposition(|byte| *byte == b'\n')
+      .map_or(0, |index| index + 1);
+    let line_end = bytes[end..]
+      .iter()
+      .position(|byte| *byte == b'\n')
+      .map_or(bytes.len(), |index| end + index);
+    let line = &mut bytes[start..line_end];
+
+    if line.iter().any(|byte| !byte.is_ascii_whitespace()) {
+      line
+        .iter_mut()
+        .filter(|byte| **byte != b'\r')
+        .for_each(|byte| *byte = b' ');
+
+      return true;
+    }
+
+    if start == 0 {
+      return false;
+    }
+
+    end = start - 1;
+  }
+}
@@ -0,0 +1,271 @@
+use oxc_ast::{
+  ast::{
+    Declaration, ForStatementInit, ForStatementLeft, FunctionType, ModuleDeclaration, Statement,
+    TSModuleDeclarationName, VariableDeclaration, VariableDeclarationKind,
+  },
+  AstKind, Visit,
+};
+use std::collections::HashSet;
+
+use crate::declarations::for_each_binding_identifier;
+
+/// The nested scopes of the node being visited, to tell whether a name refers to a top-level binding.
+///
+/// Call `enter_node` and `leave_node` from the same methods of a `Visit`.
+pub(crate) struct Scopes {
+  /// the top-level names to track
+  names: HashSet<String>,
+  /// the names declared by each nested scope, only those that shadow a tracked name
+  scopes: Vec<HashSet<String>>,
+}
+
+impl Scopes {
+  pub fn new(names: HashSet<String>) -> Self {
+    Self {
+      names,
+      scopes: vec![],
+    }
+  }
+
+  /// Whether a name refers to a tracked top-level binding, i.e. it is not shadowed.
+  pub fn is_top_level(&self, name: &str) -> bool {
+    self.names.contains(name) && !self.scopes.iter().any(|scope| scope.contains(name))
+  }
+
+  pub fn enter_node(&mut self, kind: &AstKind) {
+    if let Some(scope) = self.get_scope(kind) {
+      self.scopes.push(scope);
+    }
+  }
+
+  pub fn leave_node(&mut self, kind: &AstKind) {
+    if is_scope(kind) {
+      self.scopes.pop();
+    }
+  }
+
+  fn declare(&self, scope: &mut HashSet<String>, name: &str) {
+    if self.names.contains(name) {
+      scope.insert(name.to_string());
+    }
+  }
+
+  fn declare_variables(&self, scope: &mut HashSet<String>, decl: &VariableDeclaration) {
+    for declarator in &decl.declarations {
+      for_each_binding_identifier(&declarator.id, &mut |id| self.declare(scope, &id.name));
+    }
+  }
+
+  /// Declares the block scoped declarations of a statement list, `var`s are declared by `declare_vars`.
+  fn declare_lexical(&self, scope: &mut HashSet<String>, stmts: &[Statement]) {
+    for stmt in stmts {
+      let decl = match stmt {
+        Statement::Declaration(decl) => decl,
+        // namespace N { export let x = 1; }
+        Statement::ModuleDeclaration(module_decl) => match &**module_decl {
+          ModuleDeclaration::ExportNamedDeclaration(decl) => match &decl.declaration {
+            Some(decl) => decl,
+            None => continue,
+          },
+          _ => continue,
+        },
+        _ => continue,
+      };
+
+      match decl {
+        Declaration::VariableDeclaration(var_decl) => {
+          if var_decl.kind != VariableDeclarationKind::Var {
+            self.declare_variables(scope, var_decl);
+          }
+        }
+        Declaration::UsingDeclaration(using_decl) => {
+          for declarator in &using_decl.declarations {
+            for_each_binding_identifier(&declarator.id, &mut |id| self.declare(scope, &id.name));
+          }
+        }
+        Declaration::FunctionDeclaration(fn_decl) => {
+          if let Some(id) = &fn_decl.id {
+            self.declare(scope, &id.name);
+          }
+        }
+        Declaration::ClassDeclaration(class_decl) => {
+          if let Some(id) = &class_decl.id {
+            self.declare(scope, &id.name);
+          }
+        }
+        Declaration::TSEnumDeclaration(enum_decl) => self.declare(scope, &enum_decl.id.name),
+        Declaration::TSModuleDeclaration(module_decl) => {
+          if let TSModuleDeclarationName::Identifier(id) = &module_decl.id {
+            self.declare(scope, &id.name);
+          }
+        }
+        Declaration::TSImportEqualsDeclaration(decl) => self.declare(scope, &decl.id.name),
+        Declaration::TSTypeAliasDeclaration(_) | Declaration::TSInterfaceDeclaration(_) => (),
+      }
+    }
+  }
+
+  /// Declares the `var`s of a function body, which are hoisted out of nested blocks.
+  fn declare_vars(&self, scope: &mut HashSet<String>, stmts: &[Statement]) {
+    for name in collect_vars(stmts) {
+      self.declare(scope, &name);
+    }
+  }
+
+  /// Gets the names declared by a scope-creating node, `None` if the node does not create a scope.
+  fn get_scope(&self, kind: &AstKind) -> Option<HashSet<String>> {
+    let mut scope = HashSet::new();
+
+    match kind {
+      AstKind::Function(func) => {
+        // const f = function f() {};
+        //                    ^
+        if let (FunctionType::FunctionExpression, Some(id)) = (func.r#type, &func.id) {
+          self.declare(&mut scope, &id.name);
+        }
+
+        for param in &func.params.items {
+          for_each_binding_identifier(&param.pattern, &mut |id| self.declare(&mut scope, &id.name));
+        }
+
+        if let Some(rest) = &func.params.rest {
+          for_each_binding_identifier(&rest.argument, &mut |id| self.declare(&mut scope, &id.name));
+        }
+
+        if let Some(body) = &func.body {
+          self.declare_vars(&mut scope, &body.statements);
+          self.declare_lexical(&mut scope, &body.statements);
+        }
+      }
+      AstKind::ArrowExpression(arrow) => {
+        for param in &arrow.params.items {
+          for_each_binding_identifier(&param.pattern, &mut |id| self.declare(&mut scope, &id.name));
+        }
+
+        if let Some(rest) = &arrow.params.rest {
+          for_each_binding_identifier(&rest.argument, &mut |id| self.declare(&mut scope, &id.name));
+        }
+
+        self.declare_vars(&mut scope, &arrow.body.statements);
+        self.declare_lexical(&mut scope, &arrow.body.statements);
+      }
+      AstKind::StaticBlock(block) => {
+        self.declare_vars(&mut scope, &block.body);
+        self.declare_lexical(&mut scope, &block.body);
+      }
+      AstKind::TSModuleBlock(block) => {
+        self.declare_vars(&mut scope, &block.body);
+        self.declare_lexical(&mut scope, &block.body);
+      }
+      AstKind::BlockStatement(block) => self.declare_lexical(&mut scope, &block.body),
+      AstKind::CatchClause(clause) => {
+        if let Some(param) = &clause.param {
+          for_each_binding_identifier(param, &mut |id| self.declare(&mut scope, &id.name));
+        }
+
+        self.declare_lexical(&mut scope, &clause.body.body);
+      }
+      AstKind::SwitchStatement(stmt) => {
+        for case in &stmt.cases {
+          self.declare_lexical(&mut scope, &case.consequent);
+        }
+      }
+      // for (let i = 0; ; ) {}
+      AstKind::ForStatement(stmt) => {
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &stmt.init {
+          if decl.kind.is_lexical() {
+            self.declare_variables(&mut scope, decl);
+          }
+        }
+      }
+      // for (const x of xs) {}
+      AstKind::ForInStatement(stmt) => {
+        if let ForStatementLeft::VariableDeclaration(decl) = &stmt.left {
+          if decl.kind.is_lexical() {
+            self.declare_variables(&mut scope, decl);
+          }
+        }
+      }
+      AstKind::ForOfStatement(stmt) => {
+        if let ForStatementLeft::VariableDeclaration(decl) = &stmt.left {
+          if decl.kind.is_lexical() {
+            self.declare_variables(&mut scope, decl);
+          }
+        }
+      }
+      // the name of a class is also bound inside its body, and is immutable there
+      AstKind::Class(class) => {
+        if let Some(id) = &class.id {
+          self.declare(&mut scope, &id.name);
+        }
+      }
+      _ => return None,
+    }
+
+    Some(scope)
+  }
+}
+
+fn is_scope(kind: &AstKind) -> bool {
+  matches!(
+    kind,
+    AstKind::Function(_)
+      | AstKind::ArrowExpression(_)
+      | AstKind::StaticBlock(_)
+      | AstKind::TSModuleBlock(_)
+      | AstKind::BlockStatement(_)
+      | AstKind::CatchClause(_)
+      | AstKind::SwitchStatement(_)
+      | AstKind::ForStatement(_)
+      | AstKind::ForInStatement(_)
+      | AstKind::ForOfStatement(_)
+      | AstKind::Class(_)
+  )
+}
+
+/// Collects the names of the `var`s of a function body or a program, which are hoisted out of nested blocks.
+pub(crate) fn collect_vars(stmts: &[Statement]) -> Vec<String> {
+  let mut collector = VarCollector {
+    names: vec![],
+    function_depth: 0,
+  };
+
+  for stmt in stmts {
+    collector.visit_statement(stmt);
+  }
+
+  collector.names
+}
+
+/// Collects the names of the `var`s in statements, except those of nested functions.
+struct VarCollector {
+  names: Vec<String>,
+  /// depth of the nested functions and classes being visited
+  function_depth: u32,
+}
+
+impl<'a> Visit<'a> for VarCollector {
+  fn enter_node(&mut self, kind: AstKind<'a>) {
+    match kind {
+      AstKind::Function(_) | AstKind::ArrowExpression(_) | AstKind::Class(_) => {
+        self.function_depth += 1;
+      }
+      AstKind::VariableDeclaration(decl)
+        if self.function_depth == 0 && decl.kind == VariableDeclarationKind::Var =>
+      {
+        for declarator in &decl.declarations {
+          for_each_binding_identifier(&declarator.id, &mut |id| {
+            self.names.push(id.name.to_string())
+          });
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn leave_node(&mut self, kind: AstKind<'a>) {
+    if let AstKind::Function(_) | AstKind::ArrowExpression(_) | AstKind::Class(_) = kind {
+      self.function_depth -= 1;
+    }
+  }
+}
@@ -0,0 +1,184 @@
+use oxc_ast::{
+  ast::{ModuleDeclaration, ModuleExportName, Program, TSModuleReference, TSType, TSTypeName},
+  AstKind, Visit,
+};
+use oxc_span::Span;
+use std::collections::{HashMap, HashSet};
+
+use crate::{dependencies::get_jsx_element_reference, scope::Scopes};
+
+/// Collects the references to the imported bindings `names`, by name, in source order.
+///
+/// A reference is a byte span paired with whether it is in a type, i.e. erased from the JavaScript emit.
+/// References to a binding that is shadowed by a parameter or an inner declaration are not collected.
+pub(crate) fn collect_usages(
+  program: &Program,
+  names: HashSet<String>,
+) -> HashMap<String, Vec<(Span, bool)>> {
+  let mut collector = UsageCollector {
+    scopes: Scopes::new(names),
+    type_depth: 0,
+    usages: HashMap::new(),
+  };
+
+  collector.visit_program(program);
+
+  // heritage clauses and the `export { x }` specifiers are visited out of source order
+  for usages in collector.usages.values_mut() {
+    usages.sort_by_key(|(span, _)| span.start);
+  }
+
+  collector.usages
+}
+
+struct UsageCollector {
+  scopes: Scopes,
+  /// depth of the types being visited, the references inside them are types
+  type_depth: u32,
+  usages: HashMap<String, Vec<(Span, bool)>>,
+}
+
+impl UsageCollector {
+  fn add_usage(&mut self, name: &str, span: Span) {
+    if self.scopes.is_top_level(name) {
+      self
+        .usages
+        .entry(name.to_string())
+        .or_default()
+        .push((span, self.type_depth > 0));
+    }
+  }
+}
+
+impl<'a> Visit<'a> for UsageCollector {
+  fn enter_node(&mut self, kind: AstKind<'a>) {
+    self.scopes.enter_node(&kind);
+
+    match kind {
+      AstKind::IdentifierReference(id) => self.add_usage(&id.name, id.span),
+      AstKind::JSXElementName(name) => {
+        if let Some(id) = get_jsx_element_reference(name) {
+          self.add_usage(&id.name, id.span);
+        }
+      }
+      // export { x, type y }
+      //          ^       ^
+      AstKind::ModuleDeclaration(ModuleDeclaration::ExportNamedDeclaration(decl))
+        if decl.source.is_none() =>
+      {
+        for specifier in &decl.specifiers {
+          if let ModuleExportName::Identifier(id) = &specifier.local {
+            let is_type = decl.export_kind.is_type() || specifier.export_kind.is_type();
+
+            self.type_depth += u32::from(is_type);
+            self.add_usage(&id.name, id.span);
+            self.type_depth -= u32::from(is_type);
+          }
+        }
+      }
+      // import y = x.y;
+      //            ^
+      AstKind::TSImportEqualsDeclaration(decl) => {
+        if let TSModuleReference::TypeName(name) = &*decl.module_reference {
+          let is_type = decl.import_kind.is_type();
+
+          self.type_depth += u32::from(is_type);
+          self.visit_ts_type_name(name);
+          self.type_depth -= u32::from(is_type);
+        }
+      }
+      // class A implements B {}
+      //                    ^
+      AstKind::Class(class) => {
+        self.type_depth += 1;
+
+        for implements in class.implements.iter().flatten() {
+          self.visit_ts_type_name(&implements.expression);
+
+          if let Some(parameters) = &implements.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+          }
+        }
+
+        self.type_depth -= 1;
+      }
+      // interface A extends B {}
+      //                     ^
+      AstKind::TSInterfaceDeclaration(decl) => {
+        self.type_depth += 1;
+
+        for heritage in decl.extends.iter().flatten() {
+          self.visit_expression(&heritage.expression);
+
+          if let Some(parameters) = &heritage.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+          }
+        }
+      }
+      _ => (),
+    }
+  }
+
+  fn leave_node(&mut self, kind: AstKind<'a>) {
+    self.scopes.leave_node(&kind);
+
+    if let AstKind::TSInterfaceDeclaration(_) = kind {
+      self.type_depth -= 1;
+    }
+  }
+
+  // the default visitor skips `typeof x`, `x.Y`, import types and template literal types
+  fn visit_ts_type(&mut self, ty: &TSType<'a>) {
+    self.type_depth += 1;
+
+    match ty {
+      TSType::TSAnyKeyword(ty) => self.visit_ts_any_keyword(ty),
+      TSType::TSNullKeyword(ty) => self.visit_ts_null_keyword(ty),
+      TSType::TSVoidKeyword(ty) => self.visit_ts_void_keyword(ty),
+      TSType::TSIntersectionType(ty) => self.visit_ts_intersection_type(ty),
+      TSType::TSTypeReference(ty) => self.visit_ts_type_reference(ty),
+      TSType::TSUnionType(ty) => self.visit_ts_union_type(ty),
+      TSType::TSLiteralType(ty) => self.visit_ts_literal_type(ty),
+      TSType::TSArrayType(ty) => self.visit_ts_array_type(ty),
+      TSType::TSConditionalType(ty) => self.visit_ts_conditional_type(ty),
+      TSType::TSConstructorType(ty) => self.visit_ts_constructor_type(ty),
+      TSType::TSFunctionType(ty) => self.visit_ts_function_type(ty),
+      TSType::TSMappedType(ty) => self.visit_ts_mapped_type(ty),
+      TSType::TSTupleType(ty) => self.visit_ts_tuple_type(ty),
+      TSType::TSTypeOperatorType(ty) => self.visit_ts_type_operator_type(ty),
+      TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
+      TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
+      TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
+      // let a: typeof b;
+      //               ^
+      TSType::TSTypeQuery(ty) => {
+        self.visit_ts_type_name(&ty.expr_name);
+
+        if let Some(parameters) = &ty.type_parameters {
+          self.visit_ts_type_parameter_instantiation(parameters);
+        }
+      }
+      TSType::TSImportType(ty) => {
+        if let Some(parameters) = &ty.type_parameters {
+          self.visit_ts_type_parameter_instantiation(parameters);
+        }
+      }
+      TSType::TSTemplateLiteralType(ty) => {
+        for ty in &ty.types {
+          self.visit_ts_type(ty);
+        }
+      }
+      _ => (),
+    }
+
+    self.type_depth -= 1;
+  }
+
+  // let a: b.C;
+  //        ^
+  fn visit_ts_type_name(&mut self, name: &TSTypeName<'a>) {
+    let id = TSTypeName::get_first_name(name);
+
+    self.add_usage(&id.name, id.span);
+  }
+}
@@ -1,182 +1,1968 @@
 use oxc_ast::{
   ast::{
-    BindingIdentifier, BindingPatternKind, Declaration, ExportDefaultDeclarationKind, Expression,
-    ModuleDeclaration, Statement,
+    Argument, ArrayExpressionElement, BindingIdentifier, BindingPattern, BindingPatternKind,
+    CallExpression, Declaration, ExportDefaultDeclarationKind, Expression, ImportAttributeKey,
+    ImportDeclarationSpecifier, MemberExpression, ModuleDeclaration, ModuleExportName,
+    NewExpression, ObjectExpression, ObjectPropertyKind, PropertyKey, Statement, StringLiteral,
+    TSModuleDeclarationName, TSModuleReference, WithClause,
   },
   AstKind, Visit,
 };
-use oxc_span::GetSpan;
+use oxc_span::{Atom, GetSpan};
+use oxc_syntax::operator::BinaryOperator;
+use std::{
+  borrow::Cow,
+  collections::{HashMap, HashSet},
+};
 
-use crate::constants::{
-  BRACKET_LEFT, BRACKET_RIGHT, CURLY_BRACE_LEFT, CURLY_BRACE_RIGHT, QUOTE, SINGLE_QUOTE,
+use crate::{
+  constants::{
+    BRACKET_LEFT, BRACKET_RIGHT, CURLY_BRACE_LEFT, CURLY_BRACE_RIGHT, QUOTE, SINGLE_QUOTE,
+  },
+  declarations::{collect_declarations, DeclarationInfo},
+  dependencies::{collect_imported_names, collect_references, References},
+  folding::fold_dynamic_imports,
+  mutations::collect_mutations,
+  usages::collect_usages,
+  Diagnostic, ParseOptions,
 };
 
-#[derive(Debug)]
-pub struct Import {
-  /// name
+/// A line/column position, the column is in the selected `OffsetEncoding`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineColumn {
+  /// 1-based line
+  pub line: u32,
+  /// 0-based column
+  pub column: u32,
+}
+
+/// The start and end line/column of a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+  pub start: LineColumn,
+  pub end: LineColumn,
+}
+
+/// A range of offsets in the selected `OffsetEncoding`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+  pub start: u32,
+  pub end: u32,
+}
+
+/// What an `Import` is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+  /// `import x from 'mod'`
+  Static,
+  /// `import('mod')`
+  Dynamic {
+    /// offset of the `(`
+    paren: u32,
+  },
+  /// `import.meta`
+  ImportMeta,
+  /// `new URL('./logo.png', import.meta.url)`, see `ParseOptions::asset_urls`
+  ///
+  /// It is the `import.meta` of upstream es-module-lexer.
+  AssetUrl,
+  /// `export { x } from 'mod'`
+  ReExport,
+  /// `export * from 'mod'` and `export * as ns from 'mod'`
+  ExportStar,
+  /// `import fs = require('fs')` in TypeScript
+  ImportEquals,
+}
+
+/// The import type of upstream es-module-lexer, see `Import::t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportType {
+  Static = 1,
+  Dynamic = 2,
+  ImportMeta = 3,
+}
+
+impl From<ImportKind> for ImportType {
+  fn from(kind: ImportKind) -> Self {
+    match kind {
+      ImportKind::Static
+      | ImportKind::ReExport
+      | ImportKind::ExportStar
+      | ImportKind::ImportEquals => Self::Static,
+      ImportKind::Dynamic { .. } => Self::Dynamic,
+      ImportKind::ImportMeta | ImportKind::AssetUrl => Self::ImportMeta,
+    }
+  }
+}
+
+/// The quote character of a string literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quote {
+  /// `'mod'`
+  Single,
+  /// `"mod"`
+  Double,
+}
+
+/// A string literal in the source text, e.g. a module specifier or an arbitrary module namespace name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuotedString<'a> {
+  /// source text between the quotes, e.g. `\u0061` for `'\u0061'`
+  pub raw: Cow<'a, str>,
+  /// decoded value, e.g. `a` for `'\u0061'`
+  ///
+  /// It borrows from the source text unless it contains escapes.
+  pub value: Cow<'a, str>,
+  pub quote: Quote,
+  /// whether `raw` contains escape sequences or line continuations, so it may differ from `value`
+  pub has_escapes: bool,
+}
+
+impl QuotedString<'_> {
+  /// Converts into a `QuotedString` that owns all its data.
+  pub fn into_owned(self) -> QuotedString<'static> {
+    QuotedString {
+      raw: Cow::Owned(self.raw.into_owned()),
+      value: Cow::Owned(self.value.into_owned()),
+      ..self
+    }
+  }
+}
+
+/// A named binding in the source text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binding<'a> {
+  /// It borrows from the source text unless the name contains escapes.
+  pub name: Cow<'a, str>,
+  pub start: u32,
+  pub end: u32,
+  /// the string literal of the name, e.g. for `'a-b'` in `import { 'a-b' as c } from 'mod'`,
+  /// `None` for identifiers
+  ///
+  /// `start..end` is `literal.raw` then, it excludes the quotes.
+  pub literal: Option<QuotedString<'a>>,
+}
+
+impl Binding<'_> {
+  /// Converts into a `Binding` that owns its name.
+  pub fn into_owned(self) -> Binding<'static> {
+    Binding {
+      name: Cow::Owned(self.name.into_owned()),
+      start: self.start,
+      end: self.end,
+      literal: self.literal.map(QuotedString::into_owned),
+    }
+  }
+}
+
+/// What an `export default` exports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultExportKind {
+  /// `export default function foo() {}`
+  Function,
+  /// `export default class Foo {}`
+  Class,
+  /// `export default interface Foo {}`
+  Interface,
+  /// `export default foo` and any other expression
+  Expression,
+  /// `export default function () {}`, `export default () => {}` and function expressions
+  AnonymousFunction,
+  /// `export default class {}` and class expressions
+  AnonymousClass,
+}
+
+/// What the local binding of an `Export` is declared as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportKind {
+  Var,
+  Let,
+  /// `const` and `using` declarations
+  Const,
+  Function,
+  Class,
+  Enum,
+  Interface,
+  TypeAlias,
+  /// `namespace N {}` and `module M {}` in TypeScript
+  Namespace,
+  /// `import A = B.C` in TypeScript
+  ImportEquals,
+}
+
+/// Where a re-exported binding comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportOrigin<'a> {
+  /// specifier of the source module, `n` of the import
+  pub specifier: Cow<'a, str>,
+  /// imported name, `default` for default imports, `None` for namespaces
+  pub imported: Option<Cow<'a, str>>,
+  /// index of the import in `ParseResult::imports`
+  pub import_index: usize,
+}
+
+impl ExportOrigin<'_> {
+  /// Converts into an `ExportOrigin` that owns all its data.
+  pub fn into_owned(self) -> ExportOrigin<'static> {
+    ExportOrigin {
+      specifier: Cow::Owned(self.specifier.into_owned()),
+      imported: self.imported.map(|x| Cow::Owned(x.into_owned())),
+      import_index: self.import_index,
+    }
+  }
+}
+
+/// What the declaration of an `Export` references, directly or through other top-level declarations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportDependencies<'a> {
+  /// local names of the imported bindings, in the order of `ParseResult::imports`
+  pub imports: Vec<Cow<'a, str>>,
+  /// names of the other top-level declarations, in source order
+  pub declarations: Vec<Cow<'a, str>>,
+}
+
+impl ExportDependencies<'_> {
+  /// Converts into an `ExportDependencies` that owns all its data.
+  pub fn into_owned(self) -> ExportDependencies<'static> {
+    ExportDependencies {
+      imports: self
+        .imports
+        .into_iter()
+        .map(|x| Cow::Owned(x.into_owned()))
+        .collect(),
+      declarations: self
+        .declarations
+        .into_iter()
+        .map(|x| Cow::Owned(x.into_owned()))
+        .collect(),
+    }
+  }
+}
+
+/// The keyword of the import attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributesKeyword {
+  /// `import json from './foo.json' with { type: 'json' }`
+  With,
+  /// `import json from './foo.json' assert { type: 'json' }`, deprecated
+  Assert,
+}
+
+/// A `key: 'value'` entry of the import attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportAttribute<'a> {
+  pub key: Cow<'a, str>,
+  /// span of the key, excluding quotes
+  pub key_span: Span,
+  pub value: Cow<'a, str>,
+  /// span of the value, excluding quotes
+  pub value_span: Span,
+}
+
+/// The import attributes of an `Import`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportAttributes<'a> {
+  /// span of the attributes object, from `{` to `}`
+  ///
+  /// For dynamic imports, it is the span of the whole options object, e.g. `{ with: { type: 'json' } }`.
+  pub span: Span,
+  /// `None` for an options object of a dynamic import without a `with` or `assert` key
+  pub keyword: Option<AttributesKeyword>,
+  /// entries with a string value, in source order
+  pub entries: Vec<ImportAttribute<'a>>,
+}
+
+impl ImportAttributes<'_> {
+  /// Converts into an `ImportAttributes` that owns all its data.
+  pub fn into_owned(self) -> ImportAttributes<'static> {
+    ImportAttributes {
+      span: self.span,
+      keyword: self.keyword,
+      entries: self
+        .entries
+        .into_iter()
+        .map(|entry| ImportAttribute {
+          key: Cow::Owned(entry.key.into_owned()),
+          value: Cow::Owned(entry.value.into_owned()),
+          ..entry
+        })
+        .collect(),
+    }
+  }
+}
+
+/// The specifier of a dynamic import that is built from strings and expressions,
+/// e.g. `` import(`./locales/${lang}.json`) `` or `import('./pages/' + name + '.js')`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DynamicImportPattern<'a> {
+  /// decoded static parts, one more than `expressions`, empty strings included
+  pub quasis: Vec<Cow<'a, str>>,
+  /// spans of the expressions between the static parts
+  pub expressions: Vec<Span>,
+  /// the static parts joined with `*`, e.g. `./locales/*.json`
+  ///
+  /// Adjacent expressions share one `*`.
+  pub glob: String,
+}
+
+impl DynamicImportPattern<'_> {
+  /// Converts into a `DynamicImportPattern` that owns all its data.
+  pub fn into_owned(self) -> DynamicImportPattern<'static> {
+    DynamicImportPattern {
+      quasis: self
+        .quasis
+        .into_iter()
+        .map(|x| Cow::Owned(x.into_owned()))
+        .collect(),
+      expressions: self.expressions,
+      glob: self.glob,
+    }
+  }
+}
+
+/// Which Vite `import.meta` glob function produced an `ImportMetaGlob`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMetaGlobKind {
+  /// `import.meta.glob()`
+  Glob,
+  /// the deprecated `import.meta.globEager()`, which imports eagerly
+  GlobEager,
+}
+
+/// A Vite `import.meta.glob()` or `import.meta.globEager()` call,
+/// e.g. `import.meta.glob(['./modules/*.ts', '!**/*.test.ts'], { eager: true })`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportMetaGlob<'a> {
+  pub kind: ImportMetaGlobKind,
+  /// the glob patterns, negated patterns keep their leading `!`
+  ///
+  /// Only string literals and template literals without expressions are collected,
+  /// a single one or an array of them.
+  pub patterns: Vec<Cow<'a, str>>,
+  /// the options object, the second argument
+  pub options: Option<ImportMetaGlobOptions<'a>>,
+  /// span of the whole call, from `import` to `)`
+  pub span: Span,
+}
+
+impl ImportMetaGlob<'_> {
+  /// Converts into an `ImportMetaGlob` that owns all its data.
+  pub fn into_owned(self) -> ImportMetaGlob<'static> {
+    ImportMetaGlob {
+      patterns: self
+        .patterns
+        .into_iter()
+        .map(|x| Cow::Owned(x.into_owned()))
+        .collect(),
+      options: self.options.map(ImportMetaGlobOptions::into_owned),
+      ..self
+    }
+  }
+}
+
+/// The options object of an `ImportMetaGlob`.
+///
+/// Only literal values are collected, the others are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportMetaGlobOptions<'a> {
+  /// span of the object literal
+  pub span: Span,
+  /// `eager: true`
+  pub eager: Option<bool>,
+  /// the named export to import, e.g. `import: 'setup'`
+  pub import: Option<Cow<'a, str>>,
+  /// the query appended to the imported files, always starting with `?`
+  ///
+  /// Object queries are serialized, e.g. `query: { raw: true }` is `?raw=true`,
+  /// and the deprecated `as: 'raw'` is `?raw`.
+  pub query: Option<Cow<'a, str>>,
+  /// `exhaustive: true`, which also matches `node_modules` and dot directories
+  pub exhaustive: Option<bool>,
+  /// the base directory of the patterns, `base: './src'`
+  pub base: Option<Cow<'a, str>>,
+}
+
+impl ImportMetaGlobOptions<'_> {
+  /// Converts into an `ImportMetaGlobOptions` that owns all its data.
+  pub fn into_owned(self) -> ImportMetaGlobOptions<'static> {
+    ImportMetaGlobOptions {
+      import: self.import.map(|x| Cow::Owned(x.into_owned())),
+      query: self.query.map(|x| Cow::Owned(x.into_owned())),
+      base: self.base.map(|x| Cow::Owned(x.into_owned())),
+      ..self
+    }
+  }
+}
+
+/// What loads an `EntryPoint`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryPointKind {
+  /// `new Worker('./worker.js')`
+  Worker,
+  /// `new SharedWorker('./worker.js')`
+  SharedWorker,
+  /// `navigator.serviceWorker.register('./sw.js')`
+  ServiceWorker,
+  /// `CSS.paintWorklet.addModule('./paint.js')`
+  PaintWorklet,
+  /// `audioWorklet.addModule('./processor.js')`, also `context.audioWorklet.addModule()`
+  AudioWorklet,
+  /// `importScripts('./a.js', './b.js')` of a classic worker, an entry point for every argument
+  ImportScripts,
+}
+
+/// A script that is loaded as a worker, a service worker, a worklet or by `importScripts()`,
+/// e.g. `new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })`.
+///
+/// It is the entry point of a separate chunk rather than an import of the module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryPoint<'a> {
+  pub kind: EntryPointKind,
+  /// the specifier, if it is a string literal or `new URL()` of a string literal and `import.meta.url`
+  ///
+  /// It borrows from the source text unless the specifier contains escapes.
+  pub n: Option<Cow<'a, str>>,
+  /// span of the string literal of the specifier excluding the quotes, or of the whole argument if `n` is `None`
+  pub specifier_span: Span,
+  /// whether the specifier is `new URL('./worker.js', import.meta.url)`, which resolves relative to the module
+  /// instead of the document
+  pub is_url: bool,
+  /// the literal `type` option of workers and service workers, e.g. `module` for `{ type: 'module' }`
+  ///
+  /// Without it they are classic scripts, while worklets are always modules.
+  pub worker_type: Option<Cow<'a, str>>,
+  /// span of the whole call or `new` expression
+  pub span: Span,
+}
+
+impl EntryPoint<'_> {
+  /// Converts into an `EntryPoint` that owns all its data.
+  pub fn into_owned(self) -> EntryPoint<'static> {
+    EntryPoint {
+      n: self.n.map(|n| Cow::Owned(n.into_owned())),
+      worker_type: self.worker_type.map(|x| Cow::Owned(x.into_owned())),
+      ..self
+    }
+  }
+}
+
+/// What an `ImportBinding` imports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportBindingKind {
+  /// `import x from 'mod'`
+  Default,
+  /// `import * as x from 'mod'`, `export * as x from 'mod'` and `import x = require('mod')`
+  Namespace,
+  /// `import { x } from 'mod'` and `export { x } from 'mod'`
+  Named,
+}
+
+/// A binding imported by an `Import`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportBinding<'a> {
+  pub kind: ImportBindingKind,
+  /// imported name, only set for `ImportBindingKind::Named`
+  ///
+  /// For string literal names, e.g. `import { 'a-b' as c } from 'mod'`, the span excludes the quotes.
+  pub imported: Option<Binding<'a>>,
+  /// local name, or the exported name for re-exports
+  pub local: Binding<'a>,
+  /// whether it only imports a type, e.g. `import { type x } from 'mod'`
+  /// or any binding of `import type { x } from 'mod'`
+  pub is_type: bool,
+  /// references to the local name in the module, in source order
+  ///
+  /// Only set for `import` declarations and `import x = require()`, an unused binding has none.
+  /// References to a shadowing declaration of the same name are excluded.
+  pub references: Vec<BindingReference>,
+}
+
+impl ImportBinding<'_> {
+  /// Converts into an `ImportBinding` that owns all its data.
+  pub fn into_owned(self) -> ImportBinding<'static> {
+    ImportBinding {
+      kind: self.kind,
+      imported: self.imported.map(Binding::into_owned),
+      local: self.local.into_owned(),
+      is_type: self.is_type,
+      references: self.references,
+    }
+  }
+}
+
+/// A reference to an `ImportBinding`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BindingReference {
+  pub span: Span,
+  /// whether it is in a type, e.g. `let a: x` or `let a: typeof x`, so it is erased from the JavaScript emit
+  ///
+  /// `export type { x }` and `implements x` are types too.
+  pub is_type: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Import<'a> {
+  /// name
+  ///
+  /// For non-string dynamic import expressions
+  ///
+  /// It borrows from the source text unless the specifier contains escapes.
+  pub n: Option<Cow<'a, str>>,
+  /// whether `n` is folded from a computed dynamic import specifier, e.g. `import(base + 'x.js')`,
+  /// see `ParseOptions::fold_constants`
+  ///
+  /// `s..e` is the whole specifier expression then.
+  pub is_derived: bool,
+  /// name start
+  pub s: u32,
+  /// name end
+  ///
+  /// `s..e` excludes the quotes, so it is `literal.raw`, which differs from `n` if the specifier contains escapes.
+  /// Dynamic imports are the exception, as in upstream es-module-lexer `s..e` is the whole specifier expression,
+  /// quotes included, e.g. `'x'` for `import('x')`, so `literal.raw` is `s + 1..e - 1` there.
+  ///
+  /// For `ImportKind::AssetUrl`, `ss..se` is the whole `new URL()` expression.
+  pub e: u32,
+  /// the string literal of the specifier, set whenever `n` is
+  pub literal: Option<QuotedString<'a>>,
+  /// statement start
+  pub ss: u32,
+  /// statement end
+  pub se: u32,
+  /// dynamic index
+  /// - `-2`: import.meta and asset URLs
+  /// - `-1`: no dynamic import
+  /// - `> -1`: dynamic import
+  ///
+  /// It is derived from `kind`.
+  pub d: i32,
+  /// attributes index
+  /// - `-1`: no assertion
+  ///
+  /// It is the start of `attributes`.
+  pub a: i32,
+  /// import type of upstream es-module-lexer, derived from `kind`
+  pub t: ImportType,
+  pub kind: ImportKind,
+  pub attributes: Option<ImportAttributes<'a>>,
+  /// the pattern of a dynamic import specifier that is a template literal or a string concatenation,
+  /// whose `n` is `None`
+  pub pattern: Option<DynamicImportPattern<'a>>,
+  /// the Vite `import.meta.glob()` call of an `import.meta`
+  pub glob: Option<ImportMetaGlob<'a>>,
+  /// imported bindings, empty for dynamic imports, `import.meta` and side effect imports
+  pub bindings: Vec<ImportBinding<'a>>,
+  /// whether the whole statement only imports types and is removed from the JavaScript emit
+  ///
+  /// It is `true` for `import type`, `export type { x } from`, `export type * from`, `import type x = require()`
+  /// and for statements whose bindings are all inline `type` imports, e.g. `import { type x } from 'mod'`.
+  pub is_type: bool,
+  /// whether it is an `import 'mod'` or `import {} from 'mod'` statement, which imports nothing
+  /// and is only evaluated for its side effects
+  pub is_side_effect_only: bool,
+  /// whether the statement overlaps a parse error
+  ///
+  /// It can only be `true` in error recovery mode.
+  pub has_error: bool,
+  /// location of the name, `s..e`
+  ///
+  /// It is only set if `ParseOptions::locations` is enabled.
+  pub loc: Option<Location>,
+  /// location of the statement, `ss..se`
+  ///
+  /// It is only set if `ParseOptions::locations` is enabled.
+  pub sloc: Option<Location>,
+}
+
+impl Default for Import<'_> {
+  fn default() -> Self {
+    Self {
+      n: None,
+      is_derived: false,
+      s: 0,
+      e: 0,
+      literal: None,
+      ss: 0,
+      se: 0,
+      a: -1,
+      d: -1,
+      t: ImportType::Static,
+      kind: ImportKind::Static,
+      attributes: None,
+      pattern: None,
+      glob: None,
+      bindings: vec![],
+      is_type: false,
+      is_side_effect_only: false,
+      has_error: false,
+      loc: None,
+      sloc: None,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Export<'a> {
+  /// name start
+  pub s: u32,
+  /// name end
+  pub e: u32,
+  /// local start
+  ///
+  /// for `export { x as 'external name' } from 'external'`,
+  /// it will be -1
+  pub ls: i32,
+  /// local end
+  ///
+  /// for `export { x as 'external name' } from 'external'`,
+  /// it will be -1
+  pub le: i32,
+  /// name
+  ///
+  /// It borrows from the source text unless the name contains escapes.
+  pub n: Option<Cow<'a, str>>,
+  /// the string literal of `n` for arbitrary module namespace names, e.g. `export { x as 'a-b' }`,
+  /// `None` for identifiers
+  pub literal: Option<QuotedString<'a>>,
+  /// local name
+  ///
+  /// It borrows from the source text unless the name contains escapes.
+  pub ln: Option<Cow<'a, str>>,
+  /// local binding
+  ///
+  /// It is `ln` in `ls..le`, and is also set where es-module-lexer has no local name,
+  /// e.g. for `export default foo` and `export default interface Foo {}`.
+  pub local: Option<Binding<'a>>,
+  /// what an `export default` exports, `None` for other exports
+  pub default_kind: Option<DefaultExportKind>,
+  /// what the local binding is declared as
+  ///
+  /// For `export { x }`, `export default x` and `export = x` it is taken from the declaration of `x`
+  /// in the same file. It is `None` for re-exports, imported bindings and anonymous default exports.
+  pub kind: Option<ExportKind>,
+  /// whether it only exports a type, e.g. an interface, a type alias, a `declare` declaration,
+  /// `export type { x }` or `export { type x }`
+  ///
+  /// Every export of a `.d.ts` file is a type.
+  pub is_type: bool,
+  /// whether the local binding is a live binding that is written after its declaration,
+  /// e.g. `export let count = 0; count++`
+  ///
+  /// `export default foo` exports the value of `foo`, not the binding, so it is never mutable.
+  pub is_mutable: bool,
+  /// spans of the identifiers written to the local binding, e.g. `count` in `count++`
+  pub writes: Vec<Span>,
+  /// the import it re-exports, for `export { x } from 'mod'`, `export * as ns from 'mod'`
+  /// and for local bindings of static imports, e.g. `import { x } from 'mod'; export { x }`
+  pub origin: Option<ExportOrigin<'a>>,
+  /// what its declaration references, e.g. for `export const a = b(c)` where `b` is a top-level
+  /// function that references the imported `d`, the `declarations` are `b` and `c` and the `imports` are `d`
+  ///
+  /// It is only set if `ParseOptions::dependencies` is enabled, and not for re-exports.
+  /// References in types are ignored.
+  pub dependencies: Option<ExportDependencies<'a>>,
+  /// whether it is a TypeScript `export = foo`
+  ///
+  /// It has no name, `s..e` is the span of the exported expression.
+  pub is_export_assignment: bool,
+  /// whether the export statement overlaps a parse error
+  ///
+  /// It can only be `true` in error recovery mode.
+  pub has_error: bool,
+  /// location of the name, `s..e`
+  ///
+  /// It is only set if `ParseOptions::locations` is enabled.
+  pub loc: Option<Location>,
+}
+
+impl Default for Export<'_> {
+  fn default() -> Self {
+    Self {
+      s: 0,
+      e: 0,
+      ls: -1,
+      le: -1,
+      n: None,
+      literal: None,
+      ln: None,
+      local: None,
+      default_kind: None,
+      kind: None,
+      is_type: false,
+      is_mutable: false,
+      writes: vec![],
+      origin: None,
+      dependencies: None,
+      is_export_assignment: false,
+      has_error: false,
+      loc: None,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct ParseResult<'a> {
+  pub imports: Vec<Import<'a>>,
+  pub exports: Vec<Export<'a>>,
+  /// scripts loaded as workers, service workers, worklets or by `importScripts()`, in source order
+  pub entry_points: Vec<EntryPoint<'a>>,
+  pub facade: bool,
+  pub has_module_syntax: bool,
+  /// parse errors
+  ///
+  /// It can only be non-empty in error recovery mode.
+  pub errors: Vec<Diagnostic>,
+}
+
+impl Import<'_> {
+  /// Converts into an `Import` that owns all its data.
+  pub fn into_owned(self) -> Import<'static> {
+    Import {
+      n: self.n.map(|n| Cow::Owned(n.into_owned())),
+      literal: self.literal.map(QuotedString::into_owned),
+      attributes: self.attributes.map(ImportAttributes::into_owned),
+      pattern: self.pattern.map(DynamicImportPattern::into_owned),
+      glob: self.glob.map(ImportMetaGlob::into_owned),
+      bindings: self
+        .bindings
+        .into_iter()
+        .map(ImportBinding::into_owned)
+        .collect(),
+      ..self
+    }
+  }
+}
+
+impl Export<'_> {
+  /// Converts into an `Export` that owns all its data.
+  pub fn into_owned(self) -> Export<'static> {
+    Export {
+      n: self.n.map(|n| Cow::Owned(n.into_owned())),
+      literal: self.literal.map(QuotedString::into_owned),
+      ln: self.ln.map(|ln| Cow::Owned(ln.into_owned())),
+      local: self.local.map(Binding::into_owned),
+      origin: self.origin.map(ExportOrigin::into_owned),
+      dependencies: self.dependencies.map(ExportDependencies::into_owned),
+      ..self
+    }
+  }
+}
+
+impl ParseResult<'_> {
+  /// Converts into a `ParseResult` that owns all its data, e.g. to outlive the source text.
+  pub fn into_owned(self) -> ParseResult<'static> {
+    ParseResult {
+      imports: self.imports.into_iter().map(Import::into_owned).collect(),
+      exports: self.exports.into_iter().map(Export::into_owned).collect(),
+      entry_points: self
+        .entry_points
+        .into_iter()
+        .map(EntryPoint::into_owned)
+        .collect(),
+      ..self
+    }
+  }
+}
+
+pub struct Visitor<'a> {
+  pub source_text: &'a str,
+  /// byte offset to the offset in the selected `OffsetEncoding`
+  pub offsets: Vec<u32>,
+  /// start offsets of the lines in the selected `OffsetEncoding`, only built if `ParseOptions::locations` is enabled
+  pub line_starts: Option<Vec<u32>>,
+  /// byte spans of the parse errors, used to flag the affected imports and exports
+  pub error_spans: Vec<oxc_span::Span>,
+  /// byte span of the module declaration being visited
+  pub statement_span: oxc_span::Span,
+  /// depth of TypeScript namespace and ambient module blocks, whose module declarations are not the module's own
+  pub ts_module_block_depth: u32,
+  /// top-level declarations by name, used to classify `export { x }`
+  pub declarations: HashMap<String, DeclarationInfo>,
+  /// whether the source text is a `.d.ts` file
+  pub is_definition: bool,
+  /// byte spans of the writes to top-level bindings, by name
+  pub mutations: HashMap<String, Vec<oxc_span::Span>>,
+  /// byte spans of the references to imported bindings by name, and whether each is in a type
+  pub usages: HashMap<String, Vec<(oxc_span::Span, bool)>>,
+  /// top-level bindings referenced by the top-level declarations, only collected if `ParseOptions::dependencies` is enabled
+  pub references: Option<References>,
+  /// for each export, its own local name and the names its declaration references directly
+  pub dependency_roots: Vec<Option<(Option<String>, HashSet<String>)>>,
+  /// see `ParseOptions::skip_type_only`
+  pub skip_type_only: bool,
+  /// folded dynamic import specifiers by the byte start of the import expression,
+  /// only collected if `ParseOptions::fold_constants` is enabled
+  pub folded_imports: Option<HashMap<u32, String>>,
+  /// `import.meta.glob()` calls by the byte start of their `import.meta`, which is visited after the arguments
+  pub import_meta_globs: HashMap<u32, ImportMetaGlob<'a>>,
+  /// asset URL imports by the byte start of their `import.meta`,
+  /// only collected if `ParseOptions::asset_urls` is enabled
+  pub asset_urls: Option<HashMap<u32, Import<'a>>>,
+  /// byte starts of the `import.meta` of `new URL()` entry point specifiers, which are not asset URLs
+  pub entry_point_urls: HashSet<u32>,
+  pub result: ParseResult<'a>,
+}
+
+trait AddToExports {
+  fn add_to_exports(&self, visitor: &mut Visitor, info: DeclarationInfo);
+}
+
+impl AddToExports for BindingIdentifier {
+  fn add_to_exports(&self, visitor: &mut Visitor, info: DeclarationInfo) {
+    visitor.add_export(Export {
+      n: Some(visitor.get_name(self.span, &self.name)),
+      s: self.span.start,
+      e: self.span.end,
+      ln: Some(visitor.get_name(self.span, &self.name)),
+      ls: self.span.start as i32,
+      le: self.span.end as i32,
+      kind: Some(info.kind),
+      is_type: info.is_type,
+      ..Default::default()
+    });
+  }
+}
+
+// export const { a, b: [c = 1, ...d], ...e } = obj;
+//                ^         ^        ^      ^
+impl AddToExports for BindingPattern<'_> {
+  fn add_to_exports(&self, visitor: &mut Visitor, info: DeclarationInfo) {
+    match &self.kind {
+      BindingPatternKind::BindingIdentifier(id) => id.add_to_exports(visitor, info),
+      BindingPatternKind::ObjectPattern(pattern) => {
+        for property in &pattern.properties {
+          property.value.add_to_exports(visitor, info);
+        }
+
+        if let Some(rest) = &pattern.rest {
+          rest.argument.add_to_exports(visitor, info);
+        }
+      }
+      BindingPatternKind::ArrayPattern(pattern) => {
+        for element in pattern.elements.iter().flatten() {
+          element.add_to_exports(visitor, info);
+        }
+
+        if let Some(rest) = &pattern.rest {
+          rest.argument.add_to_exports(visitor, info);
+        }
+      }
+      BindingPatternKind::AssignmentPattern(pattern) => pattern.left.add_to_exports(visitor, info),
+    }
+  }
+}
+
+/// Gets the byte span of the value of a string literal, excluding the quotes.
+fn get_string_literal_span(literal: &StringLiteral) -> Span {
+  Span {
+    start: literal.span.start + 1,
+    end: literal.span.end - 1,
+  }
+}
+
+fn get_span(span: oxc_span::Span) -> Span {
+  Span {
+    start: span.start,
+    end: span.end,
+  }
+}
+
+/// Collects the operands of a chain of `+`, e.g. `a`, `b` and `c` for `a + b + c`.
+///
+/// As `+` is left-associative, only left operands are flattened, e.g. `a` and `(b + c)` for `a + (b + c)`.
+fn flatten_addition<'a, 'b>(expr: &'b Expression<'a>, operands: &mut Vec<&'b Expression<'a>>) {
+  match expr {
+    Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+      flatten_addition(&expr.left, operands);
+      operands.push(&expr.right);
+    }
+    _ => operands.push(expr),
+  }
+}
+
+/// Gets the name of an identifier or the property of a static member expression, e.g. `c` for `a.b.c`.
+fn get_static_name<'b>(expr: &'b Expression) -> Option<&'b str> {
+  match expr {
+    Expression::Identifier(id) => Some(id.name.as_str()),
+    Expression::MemberExpression(member) => match &**member {
+      MemberExpression::StaticMemberExpression(member) => Some(member.property.name.as_str()),
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+fn get_boolean(expr: &Expression) -> Option<bool> {
+  match expr {
+    Expression::BooleanLiteral(literal) => Some(literal.value),
+    _ => None,
+  }
+}
+
+/// Whether an import only imports types, i.e. it has bindings and all of them are types.
+fn is_all_type(bindings: &[ImportBinding]) -> bool {
+  !bindings.is_empty() && bindings.iter().all(|binding| binding.is_type)
+}
+
+enum FindIndexByCharType {
+  Last,
+  Next,
+}
+
+impl<'a> Visitor<'a> {
+  pub fn new(source_text: &'a str, options: &ParseOptions) -> Self {
+    // one more slot for the end of the source text
+    let mut offsets = vec![0; source_text.len() + 1];
+    let mut line_starts = options.locations.then(|| vec![0]);
+    let mut offset = 0;
+
+    for (byte_index, ch) in source_text.char_indices() {
+      offsets[byte_index..byte_index + ch.len_utf8()]
+        .iter_mut()
+        .for_each(|x| *x = offset);
+
+      offset += options.offset_encoding.char_len(ch);
+
+      if ch == '\n' {
+        if let Some(line_starts) = &mut line_starts {
+          line_starts.push(offset);
+        }
+      }
+    }
+
+    offsets[source_text.len()] = offset;
+
+    Self {
+      source_text,
+      offsets,
+      line_starts,
+      error_spans: vec![],
+      statement_span: oxc_span::Span::default(),
+      ts_module_block_depth: 0,
+      declarations: HashMap::new(),
+      mutations: HashMap::new(),
+      usages: HashMap::new(),
+      references: options.dependencies.then(References::default),
+      dependency_roots: vec![],
+      is_definition: false,
+      skip_type_only: options.skip_type_only,
+      folded_imports: options.fold_constants.then(HashMap::new),
+      import_meta_globs: HashMap::new(),
+      asset_urls: options.asset_urls.then(HashMap::new),
+      entry_point_urls: HashSet::new(),
+      result: ParseResult {
+        imports: vec![],
+        exports: vec![],
+        entry_points: vec![],
+        facade: false,
+        has_module_syntax: false,
+        errors: vec![],
+      },
+    }
+  }
+
+  fn has_error(&self, start: u32, end: u32) -> bool {
+    self
+      .error_spans
+      .iter()
+      .any(|span| span.start <= end && span.end >= start)
+  }
+
+  /// Gets the location of a range of offsets in the selected `OffsetEncoding`.
+  fn get_location(&self, start: u32, end: u32) -> Option<Location> {
+    let line_starts = self.line_starts.as_ref()?;
+    let get_line_column = |offset: u32| {
+      let line = line_starts.partition_point(|x| *x <= offset) - 1;
+
+      LineColumn {
+        line: line as u32 + 1,
+        column: offset - line_starts[line],
+      }
+    };
+
+    Some(Location {
+      start: get_line_column(start),
+      end: get_line_column(end),
+    })
+  }
+
+  /// Adds an import and returns its index, `None` if it is skipped.
+  fn add_import(&mut self, import: Import<'a>) -> Option<usize> {
+    if import.is_type && self.skip_type_only {
+      return None;
+    }
+
+    let has_error = self.has_error(import.ss, import.se);
+    let s = self.get_offset(import.s);
+    let e = self.get_offset(import.e);
+    let ss = self.get_offset(import.ss);
+    let se = self.get_offset(import.se);
+    let kind = match import.kind {
+      ImportKind::Dynamic { paren } => ImportKind::Dynamic {
+        paren: self.get_offset(paren),
+      },
+      kind => kind,
+    };
+    let attributes = import.attributes.map(|attributes| ImportAttributes {
+      span: self.convert_span(attributes.span),
+      keyword: attributes.keyword,
+      entries: attributes
+        .entries
+        .into_iter()
+        .map(|entry| ImportAttribute {
+          key_span: self.convert_span(entry.key_span),
+          value_span: self.convert_span(entry.value_span),
+          ..entry
+        })
+        .collect(),
+    });
+    let pattern = import.pattern.map(|pattern| DynamicImportPattern {
+      expressions: pattern
+        .expressions
+        .into_iter()
+        .map(|span| self.convert_span(span))
+        .collect(),
+      ..pattern
+    });
+    let glob = import.glob.map(|glob| ImportMetaGlob {
+      options: glob.options.map(|options| ImportMetaGlobOptions {
+        span: self.convert_span(options.span),
+        ..options
+      }),
+      span: self.convert_span(glob.span),
+      ..glob
+    });
+    let bindings = import
+      .bindings
+      .into_iter()
+      .map(|binding| ImportBinding {
+        kind: binding.kind,
+        imported: binding.imported.map(|x| self.convert_binding(x)),
+        local: self.convert_binding(binding.local),
+        is_type: binding.is_type,
+        references: binding
+          .references
+          .into_iter()
+          .map(|reference| BindingReference {
+            span: self.convert_span(reference.span),
+            ..reference
+          })
+          .collect(),
+      })
+      .collect();
+
+    self.result.imports.push(Import {
+      n: import.n,
+      is_derived: import.is_derived,
+      s,
+      e,
+      literal: import.literal,
+      ss,
+      se,
+      a: attributes
+        .as_ref()
+        .map_or(-1, |attributes| attributes.span.start as i32),
+      d: match kind {
+        ImportKind::Dynamic { paren } => paren as i32,
+        ImportKind::ImportMeta | ImportKind::AssetUrl => -2,
+        _ => -1,
+      },
+      t: kind.into(),
+      kind,
+      attributes,
+      pattern,
+      glob,
+      bindings,
+      is_type: import.is_type,
+      is_side_effect_only: import.is_side_effect_only,
+      has_error,
+      loc: self.get_location(s, e),
+      sloc: self.get_location(ss, se),
+    });
+
+    Some(self.result.imports.len() - 1)
+  }
+
+  fn add_entry_point(&mut self, entry_point: EntryPoint<'a>) {
+    self.result.entry_points.push(EntryPoint {
+      specifier_span: self.convert_span(entry_point.specifier_span),
+      span: self.convert_span(entry_point.span),
+      ..entry_point
+    });
+  }
+
+  fn add_export(&mut self, export: Export<'a>) {
+    let is_type = export.is_type || self.is_definition;
+
+    if is_type && self.skip_type_only {
+      return;
+    }
+
+    let has_error = self.has_error(self.statement_span.start, self.statement_span.end);
+
+    let s = self.get_offset(export.s);
+    let e = self.get_offset(export.e);
+    let ls = self.get_optional_offset(export.ls);
+    let le = self.get_optional_offset(export.le);
+    let local = match export.local {
+      Some(local) => Some(self.convert_binding(local)),
+      None => export.ln.clone().filter(|_| ls >= 0).map(|name| Binding {
+        name,
+        start: ls as u32,
+        end: le as u32,
+        literal: None,
+      }),
+    };
+
+    // export let count = 0; count++;
+    //                        ^^^^^
+    let writes = match &local {
+      Some(local)
+        if export.kind.is_some()
+          && export.default_kind != Some(DefaultExportKind::Expression)
+          && !export.is_export_assignment =>
+      {
+        self
+          .mutations
+          .get(local.name.as_ref())
+          .map_or(vec![], |spans| {
+            spans
+              .iter()
+              .map(|span| Span {
+                start: self.get_offset(span.start),
+                end: self.get_offset(span.end),
+              })
+              .collect()
+          })
+      }
+      _ => vec![],
+    };
+
+    if let Some(references) = &self.references {
+      let root = if export.origin.is_some() {
+        None
+      } else if local.is_none()
+        || export.default_kind == Some(DefaultExportKind::Expression)
+        || export.is_export_assignment
+      {
+        // export default a + b;
+        //                ^   ^
+        // export default function () { return a; }
+        //                                     ^
+        let start = self.statement_span.start;
+
+        Some((
+          None,
+          references
+            .by_statement
+            .get(&start)
+            .cloned()
+            .unwrap_or_default(),
+        ))
+      } else {
+        local
+          .as_ref()
+          .filter(|_| export.kind.is_some())
+          .map(|local| {
+            let name = local.name.to_string();
+            let direct = references.by_name.get(&name).cloned().unwrap_or_default();
+
+            (Some(name), direct)
+          })
+      };
+
+      self.dependency_roots.push(root);
+    }
+
+    self.result.exports.push(Export {
+      n: export.n,
+      literal: export.literal,
+      ln: export.ln,
+      local,
+      default_kind: export.default_kind,
+      kind: export.kind,
+      is_type,
+      is_mutable: !writes.is_empty(),
+      writes,
+      origin: export.origin,
+      dependencies: None,
+      s,
+      e,
+      ls,
+      le,
+      is_export_assignment: export.is_export_assignment,
+      has_error,
+      loc: self.get_location(s, e),
+    })
+  }
+
+  /// Sets the `origin` of the exports of local bindings that are imported,
+  /// e.g. `import { x } from 'mod'; export { x }`.
+  ///
+  /// It runs after the whole program is visited, as imports are hoisted and may come after the export.
+  fn resolve_export_origins(&mut self) {
+    let mut imported_bindings = HashMap::new();
+
+    for (import_index, import) in self.result.imports.iter().enumerate() {
+      let (ImportKind::Static | ImportKind::ImportEquals, Some(specifier)) =
+        (import.kind, &import.n)
+      else {
+        continue;
+      };
+
+      for binding in &import.bindings {
+        let imported = match binding.kind {
+          ImportBindingKind::Default => Some(Cow::Borrowed("default")),
+          ImportBindingKind::Namespace => None,
+          ImportBindingKind::Named => binding.imported.as_ref().map(|x| x.name.clone()),
+        };
+
+        imported_bindings.insert(
+          binding.local.name.as_ref(),
+          ExportOrigin {
+            specifier: specifier.clone(),
+            imported,
+            import_index,
+          },
+        );
+      }
+    }
+
+    for export in &mut self.result.exports {
+      if export.origin.is_some() || export.kind.is_some() {
+        continue;
+      }
+
+      if let Some(local) = &export.local {
+        export.origin = imported_bindings.get(local.name.as_ref()).cloned();
+      }
+    }
+  }
+
+  /// Sets the `dependencies` of the exports from the references of their declarations.
+  ///
+  /// It runs after the whole program is visited, to list the imports in order.
+  fn resolve_export_dependencies(&mut self) {
+    let Some(references) = &self.references else {
+      return;
+    };
+
+    for (export, root) in self.result.exports.iter_mut().zip(&self.dependency_roots) {
+      let Some((name, direct)) = root else {
+        continue;
+      };
+
+      // follow the references through the top-level declarations
+      let mut names = HashSet::new();
+      let mut queue = direct.iter().collect::<Vec<_>>();
+
+      while let Some(reference) = queue.pop() {
+        if names.insert(reference.as_str()) {
+          if let Some(references) = references.by_name.get(reference) {
+            queue.extend(references);
+          }
+        }
+      }
+
+      if let Some(name) = name {
+        names.remove(name.as_str());
+      }
+
+      let mut dependencies = ExportDependencies {
+        imports: vec![],
+        declarations: vec![],
+      };
+
+      for import in &self.result.imports {
+        for binding in &import.bindings {
+          let local = binding.local.name.as_ref();
+
+          if matches!(import.kind, ImportKind::Static | ImportKind::ImportEquals)
+            && names.remove(local)
+          {
+            dependencies.imports.push(binding.local.name.clone());
+          }
+        }
+      }
+
+      let mut declarations = names
+        .into_iter()
+        .filter_map(|name| Some((name, references.spans.get(name)?)))
+        .collect::<Vec<_>>();
+
+      declarations.sort_by_key(|(_, span)| span.start);
+      dependencies.declarations = declarations
+        .into_iter()
+        .map(
+          |(name, span)| match self.source_text.get(span.start as usize..span.end as usize) {
+            Some(raw) if raw == name => Cow::Borrowed(raw),
+            _ => Cow::Owned(name.to_string()),
+          },
+        )
+        .collect();
+
+      export.dependencies = Some(dependencies);
+    }
+  }
+
+  /// Sets the `kind` and `is_type` of an export of a local binding from its declaration.
+  fn classify_export(&self, export: &mut Export, local: &str) {
+    if let Some(info) = self.declarations.get(local) {
+      export.kind = Some(info.kind);
+      export.is_type = info.is_type;
+    }
+  }
+
+  /// Gets the references to an imported binding, with byte spans.
+  fn get_references(&self, name: &str) -> Vec<BindingReference> {
+    self.usages.get(name).map_or(vec![], |usages| {
+      usages
+        .iter()
+        .map(|(span, is_type)| BindingReference {
+          span: Span {
+            start: span.start,
+            end: span.end,
+          },
+          is_type: *is_type,
+        })
+        .collect()
+    })
+  }
+
+  /// Gets a name, borrowed from the source text if it is written as is (e.g. without escapes).
+  fn get_name(&self, span: oxc_span::Span, value: &Atom) -> Cow<'a, str> {
+    match self.source_text.get(span.start as usize..span.end as usize) {
+      Some(raw) if raw == value.as_str() => Cow::Borrowed(raw),
+      _ => Cow::Owned(value.to_string()),
+    }
+  }
+
+  fn get_string_literal_value(&self, literal: &StringLiteral) -> Cow<'a, str> {
+    // 'mod'
+    //  ^^^
+    let span = oxc_span::Span::new(literal.span.start + 1, literal.span.end - 1);
+
+    self.get_name(span, &literal.value)
+  }
+
+  fn get_module_export_name(&self, name: &ModuleExportName) -> Cow<'a, str> {
+    match name {
+      ModuleExportName::Identifier(id) => self.get_name(id.span, &id.name),
+      ModuleExportName::StringLiteral(literal) => self.get_string_literal_value(literal),
+    }
+  }
+
+  /// Gets a `Binding` with byte offsets, string literal names exclude the quotes.
+  fn get_module_export_binding(&self, name: &ModuleExportName) -> Binding<'a> {
+    let span = match name {
+      ModuleExportName::Identifier(id) => id.span,
+      ModuleExportName::StringLiteral(literal) => {
+        oxc_span::Span::new(literal.span.start + 1, literal.span.end - 1)
+      }
+    };
+
+    Binding {
+      name: self.get_module_export_name(name),
+      start: span.start,
+      end: span.end,
+      literal: self.get_module_export_literal(name),
+    }
+  }
+
+  fn get_module_export_literal(&self, name: &ModuleExportName) -> Option<QuotedString<'a>> {
+    match name {
+      ModuleExportName::Identifier(_) => None,
+      ModuleExportName::StringLiteral(literal) => Some(self.get_quoted_string(literal)),
+    }
+  }
+
+  fn get_quoted_string(&self, literal: &StringLiteral) -> QuotedString<'a> {
+    // 'mod'
+    //  ^^^
+    let raw = &self.source_text[literal.span.start as usize + 1..literal.span.end as usize - 1];
+    let quote = match self.get_ascii_char(literal.span.start as usize) {
+      Some(QUOTE) => Quote::Double,
+      _ => Quote::Single,
+    };
+
+    QuotedString {
+      raw: Cow::Borrowed(raw),
+      value: self.get_string_literal_value(literal),
+      quote,
+      has_escapes: raw.contains('\\'),
+    }
+  }
+
+  /// Gets the pattern of a template literal or a string concatenation, with byte offsets.
+  ///
+  /// Returns `None` for other expressions, for additions whose first two operands are not strings,
+  /// e.g. `a + b + '.js'`, as they may not be string concatenations,
+  /// and for patterns without a static part, e.g. `${a}`, as they would match anything.
+  fn get_dynamic_import_pattern(&self, expr: &Expression) -> Option<DynamicImportPattern<'a>> {
+    let mut operands = vec![];
+
+    flatten_addition(expr, &mut operands);
+
+    let is_string = |expr: &Expression| {
+      matches!(
+        expr,
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_)
+      )
+    };
+
+    match operands.as_slice() {
+      [Expression::TemplateLiteral(_)] => {}
+      [first, second, ..] if is_string(first) || is_string(second) => {}
+      _ => return None,
+    }
+
+    let mut quasis = vec![Cow::Borrowed("")];
+    let mut expressions = vec![];
+    let append = |quasis: &mut Vec<Cow<'a, str>>, value: Cow<'a, str>| {
+      let last = quasis.last_mut().unwrap();
+
+      if last.is_empty() {
+        *last = value;
+      } else {
+        last.to_mut().push_str(&value);
+      }
+    };
+
+    for operand in operands {
+      match operand {
+        Expression::StringLiteral(literal) => {
+          append(&mut quasis, self.get_string_literal_value(literal))
+        }
+        // `./${a}/${b}.js`
+        //  ^^ ^ ^^ ^ ^^^
+        Expression::TemplateLiteral(literal) => {
+          for (index, quasi) in literal.quasis.iter().enumerate() {
+            if let Some(expr) = index
+              .checked_sub(1)
+              .and_then(|i| literal.expressions.get(i))
+            {
+              expressions.push(get_span(expr.span()));
+              quasis.push(Cow::Borrowed(""));
+            }
+
+            let value = quasi.value.cooked.as_ref().unwrap_or(&quasi.value.raw);
+
+            append(&mut quasis, self.get_name(quasi.span, value));
+          }
+        }
+        expr => {
+          expressions.push(get_span(expr.span()));
+          quasis.push(Cow::Borrowed(""));
+        }
+      }
+    }
+
+    if quasis.iter().all(|quasi| quasi.is_empty()) {
+      return None;
+    }
+
+    let mut glob = String::new();
+
+    for (index, quasi) in quasis.iter().enumerate() {
+      if index > 0 && !glob.ends_with('*') {
+        glob.push('*');
+      }
+
+      glob.push_str(quasi);
+    }
+
+    Some(DynamicImportPattern {
+      quasis,
+      expressions,
+      glob,
+    })
+  }
+
+  /// Gets the asset URL import of a `new URL('./logo.png', import.meta.url)` expression
+  /// and the byte start of its `import.meta`, with byte offsets.
+  ///
+  /// A specifier that is not a string literal has no name, but a `pattern` if it is a template literal
+  /// or a string concatenation.
+  fn get_asset_url(&self, expr: &NewExpression) -> Option<(u32, Import<'a>)> {
+    // new URL('./logo.png', import.meta.url)
+    //     ^^^ ^^^^^^^^^^^^  ^^^^^^^^^^^ ^^^
+    let Expression::Identifier(callee) = &expr.callee else {
+      return None;
+    };
+    let [Argument::Expression(specifier), Argument::Expression(base)] = expr.arguments.as_slice()
+    else {
+      return None;
+    };
+    let Expression::MemberExpression(member) = base.get_inner_expression() else {
+      return None;
+    };
+    let MemberExpression::StaticMemberExpression(member) = &**member else {
+      return None;
+    };
+    let Expression::MetaProperty(meta) = member.object.get_inner_expression() else {
+      return None;
+    };
+
+    if callee.name != "URL"
+      || member.property.name != "url"
+      || meta.meta.name != "import"
+      || meta.property.name != "meta"
+    {
+      return None;
+    }
+
+    let mut import = Import {
+      kind: ImportKind::AssetUrl,
+      ss: expr.span.start,
+      se: expr.span.end,
+      s: specifier.span().start,
+      e: specifier.span().end,
+      ..Default::default()
+    };
+
+    if let Expression::StringLiteral(literal) = specifier {
+      let span = get_string_literal_span(literal);
+
+      import.n = Some(self.get_string_literal_value(literal));
+      import.s = span.start;
+      import.e = span.end;
+      import.literal = Some(self.get_quoted_string(literal));
+    } else {
+      // new URL(`./icons/${name}.svg`, import.meta.url)
+      //         ^^^^^^^^^^^^^^^^^^^^^
+      import.pattern = self.get_dynamic_import_pattern(specifier);
+    }
+
+    Some((meta.span.start, import))
+  }
+
+  /// Gets the entry points of a `navigator.serviceWorker.register()`, `CSS.paintWorklet.addModule()`,
+  /// `audioWorklet.addModule()` or `importScripts()` call, with byte offsets.
+  fn get_call_entry_points(&mut self, call: &CallExpression) -> Vec<EntryPoint<'a>> {
+    let kind = match &call.callee {
+      // importScripts('./a.js', './b.js')
+      Expression::Identifier(id) if id.name == "importScripts" => {
+        return call
+          .arguments
+          .iter()
+          .filter_map(|argument| {
+            self.get_entry_point(
+              EntryPointKind::ImportScripts,
+              std::slice::from_ref(argument),
+              call.span,
+            )
+          })
+          .collect();
+      }
+      Expression::MemberExpression(member) => {
+        let MemberExpression::StaticMemberExpression(member) = &**member else {
+          return vec![];
+        };
+
+        match (
+          get_static_name(&member.object),
+          member.property.name.as_str(),
+        ) {
+          (Some("serviceWorker"), "register") => EntryPointKind::ServiceWorker,
+          (Some("paintWorklet"), "addModule") => EntryPointKind::PaintWorklet,
+          (Some("audioWorklet"), "addModule") => EntryPointKind::AudioWorklet,
+          _ => return vec![],
+        }
+      }
+      _ => return vec![],
+    };
+
+    self
+      .get_entry_point(kind, &call.arguments, call.span)
+      .into_iter()
+      .collect()
+  }
+
+  /// Gets the `EntryPoint` of the arguments of a worker constructor or a loading function, with byte offsets.
   ///
-  /// For non-string dynamic import expressions
-  pub n: Option<String>,
-  /// name start
-  pub s: u32,
-  /// name end
-  pub e: u32,
-  /// statement start
-  pub ss: u32,
-  /// statement end
-  pub se: u32,
-  /// dynamic index
-  /// - `-2`: import.meta
-  /// - `-1`: no dynamic import
-  /// - `> -1`: dynamic import
-  pub d: i32,
-  /// attributes index
-  /// - `-1`: no assertion
-  pub a: i32,
-}
-
-impl Default for Import {
-  fn default() -> Self {
-    Self {
+  /// A `new URL()` specifier is remembered in `entry_point_urls`, so that it is not an asset URL as well.
+  fn get_entry_point(
+    &mut self,
+    kind: EntryPointKind,
+    arguments: &[Argument],
+    span: oxc_span::Span,
+  ) -> Option<EntryPoint<'a>> {
+    let Some(Argument::Expression(specifier)) = arguments.first() else {
+      return None;
+    };
+    let mut entry_point = EntryPoint {
+      kind,
       n: None,
-      s: 0,
-      e: 0,
-      ss: 0,
-      se: 0,
-      a: -1,
-      d: -1,
+      specifier_span: get_span(specifier.span()),
+      is_url: false,
+      worker_type: None,
+      span: get_span(span),
+    };
+
+    match specifier {
+      // new Worker('./worker.js')
+      //             ^^^^^^^^^^^
+      Expression::StringLiteral(literal) => {
+        entry_point.n = Some(self.get_string_literal_value(literal));
+        entry_point.specifier_span = get_string_literal_span(literal);
+      }
+      // new Worker(new URL('./worker.js', import.meta.url))
+      //                     ^^^^^^^^^^^
+      Expression::NewExpression(expr) => {
+        if let Some((start, import)) = self.get_asset_url(expr) {
+          self.entry_point_urls.insert(start);
+          entry_point.n = import.n;
+          entry_point.specifier_span = Span {
+            start: import.s,
+            end: import.e,
+          };
+          entry_point.is_url = true;
+        }
+      }
+      _ => (),
+    }
+
+    // new Worker('./worker.js', { type: 'module' })
+    //                                   ^^^^^^^^
+    if let (
+      EntryPointKind::Worker | EntryPointKind::SharedWorker | EntryPointKind::ServiceWorker,
+      Some(Argument::Expression(Expression::ObjectExpression(options))),
+    ) = (kind, arguments.get(1))
+    {
+      for property in &options.properties {
+        if let ObjectPropertyKind::ObjectProperty(property) = property {
+          if let Some((key, _)) = self.get_property_key(&property.key) {
+            if key == "type" {
+              entry_point.worker_type = self.get_static_string(&property.value);
+            }
+          }
+        }
+      }
     }
+
+    Some(entry_point)
   }
-}
 
-#[derive(Debug)]
-pub struct Export {
-  /// name start
-  pub s: u32,
-  /// name end
-  pub e: u32,
-  /// local start
-  ///
-  /// for `export { x as 'external name' } from 'external'`,
-  /// it will be -1
-  pub ls: i32,
-  /// local end
-  ///
-  /// for `export { x as 'external name' } from 'external'`,
-  /// it will be -1
-  pub le: i32,
-  /// name
-  pub n: Option<String>,
-  /// local name
-  pub ln: Option<String>,
-}
+  /// Gets the `ImportMetaGlob` of an `import.meta.glob()` call and the byte start of its `import.meta`,
+  /// with byte offsets.
+  fn get_import_meta_glob(&self, call: &CallExpression) -> Option<(u32, ImportMetaGlob<'a>)> {
+    // import.meta.glob('./modules/*.ts')
+    // ^^^^^^^^^^^ ^^^^
+    let Expression::MemberExpression(member) = &call.callee else {
+      return None;
+    };
+    let MemberExpression::StaticMemberExpression(member) = &**member else {
+      return None;
+    };
+    let Expression::MetaProperty(meta) = member.object.get_inner_expression() else {
+      return None;
+    };
 
-impl Default for Export {
-  fn default() -> Self {
-    Self {
-      s: 0,
-      e: 0,
-      ls: -1,
-      le: -1,
-      n: None,
-      ln: None,
+    if meta.meta.name != "import" || meta.property.name != "meta" {
+      return None;
+    }
+
+    let kind = match member.property.name.as_str() {
+      "glob" => ImportMetaGlobKind::Glob,
+      "globEager" => ImportMetaGlobKind::GlobEager,
+      _ => return None,
+    };
+    let mut patterns = vec![];
+
+    // import.meta.glob(['./modules/*.ts', '!**/*.test.ts'])
+    //                   ^^^^^^^^^^^^^^^^  ^^^^^^^^^^^^^^^
+    match call.arguments.first() {
+      Some(Argument::Expression(Expression::ArrayExpression(array))) => {
+        for element in &array.elements {
+          if let ArrayExpressionElement::Expression(expr) = element {
+            patterns.extend(self.get_static_string(expr));
+          }
+        }
+      }
+      Some(Argument::Expression(expr)) => patterns.extend(self.get_static_string(expr)),
+      _ => (),
     }
+
+    let options = match call.arguments.get(1) {
+      Some(Argument::Expression(Expression::ObjectExpression(obj_expr))) => {
+        Some(self.get_import_meta_glob_options(obj_expr))
+      }
+      _ => None,
+    };
+
+    Some((
+      meta.span.start,
+      ImportMetaGlob {
+        kind,
+        patterns,
+        options,
+        span: get_span(call.span),
+      },
+    ))
   }
-}
 
-#[derive(Debug)]
-pub struct ParseResult {
-  pub imports: Vec<Import>,
-  pub exports: Vec<Export>,
-  pub facade: bool,
-  pub has_module_syntax: bool,
-}
+  /// Gets the `ImportMetaGlobOptions` of the options object of an `import.meta.glob()` call, with byte offsets.
+  fn get_import_meta_glob_options(&self, options: &ObjectExpression) -> ImportMetaGlobOptions<'a> {
+    let mut glob_options = ImportMetaGlobOptions {
+      span: get_span(options.span),
+      eager: None,
+      import: None,
+      query: None,
+      exhaustive: None,
+      base: None,
+    };
+    let mut as_query = None;
 
-pub struct Visitor<'a> {
-  pub source_text: &'a str,
-  pub source_text_chars: Vec<char>,
-  pub byte_to_char: Vec<usize>,
-  pub result: ParseResult,
-}
+    // import.meta.glob('./*.ts', { eager: true, import: 'setup', query: '?raw' })
+    //                              ^^^^^  ^^^^  ^^^^^^  ^^^^^^^  ^^^^^  ^^^^^^
+    for property in &options.properties {
+      let ObjectPropertyKind::ObjectProperty(property) = property else {
+        continue;
+      };
+      let Some((key, _)) = self.get_property_key(&property.key) else {
+        continue;
+      };
+      let value = property.value.get_inner_expression();
 
-trait AddToExports {
-  fn add_to_exports(&self, visitor: &mut Visitor);
-}
+      match key.as_ref() {
+        "eager" => glob_options.eager = get_boolean(value),
+        "exhaustive" => glob_options.exhaustive = get_boolean(value),
+        "import" => glob_options.import = self.get_static_string(value),
+        "base" => glob_options.base = self.get_static_string(value),
+        "as" => as_query = self.get_static_string(value),
+        "query" => {
+          glob_options.query = match value {
+            Expression::ObjectExpression(query) => self.get_import_meta_glob_query(query),
+            value => self.get_static_string(value),
+          }
+          .map(|query| {
+            if query.starts_with('?') {
+              query
+            } else {
+              Cow::Owned(format!("?{query}"))
+            }
+          })
+        }
+        _ => (),
+      }
+    }
 
-impl AddToExports for BindingIdentifier {
-  fn add_to_exports(&self, visitor: &mut Visitor) {
-    visitor.add_export(Export {
-      n: Some(self.name.to_string()),
-      s: self.span.start,
-      e: self.span.end,
-      ln: Some(self.name.to_string()),
-      ls: self.span.start as i32,
-      le: self.span.end as i32,
-    });
+    // { as: 'raw' }
+    //       ^^^^^
+    if glob_options.query.is_none() {
+      glob_options.query = as_query.map(|query| Cow::Owned(format!("?{query}")));
+    }
+
+    glob_options
   }
-}
 
-enum FindIndexByCharType {
-  Last,
-  Next,
-}
+  /// Serializes an object query of `import.meta.glob()`, e.g. `?raw=true&lang=ts` for `{ raw: true, lang: 'ts' }`.
+  ///
+  /// Returns `None` if a value is not a literal.
+  fn get_import_meta_glob_query(&self, query: &ObjectExpression) -> Option<Cow<'a, str>> {
+    let mut entries = vec![];
 
-impl<'a> Visitor<'a> {
-  pub fn new(source_text: &'a str) -> Self {
-    let mut byte_to_char = vec![0; source_text.len()];
+    for property in &query.properties {
+      let ObjectPropertyKind::ObjectProperty(property) = property else {
+        return None;
+      };
+      let (key, _) = self.get_property_key(&property.key)?;
+      let value = match property.value.get_inner_expression() {
+        Expression::BooleanLiteral(literal) => literal.value.to_string(),
+        Expression::NumberLiteral(literal) => literal.raw.to_string(),
+        value => self.get_static_string(value)?.into_owned(),
+      };
 
-    for (char_index, (byte_index, ch)) in source_text.char_indices().enumerate() {
-      byte_to_char[byte_index..byte_index + ch.len_utf8()]
-        .iter_mut()
-        .for_each(|x| *x = char_index);
+      entries.push(format!("{key}={value}"));
     }
 
-    Self {
-      source_text,
-      source_text_chars: source_text.chars().collect(),
-      byte_to_char,
-      result: ParseResult {
-        imports: vec![],
-        exports: vec![],
-        facade: false,
-        has_module_syntax: false,
+    Some(Cow::Owned(format!("?{}", entries.join("&"))))
+  }
+
+  /// Gets the value of a string literal or a template literal without expressions.
+  fn get_static_string(&self, expr: &Expression) -> Option<Cow<'a, str>> {
+    match expr {
+      Expression::StringLiteral(literal) => Some(self.get_string_literal_value(literal)),
+      Expression::TemplateLiteral(literal) if literal.expressions.is_empty() => {
+        let quasi = literal.quasis.first()?;
+
+        Some(self.get_name(quasi.span, quasi.value.cooked.as_ref()?))
+      }
+      _ => None,
+    }
+  }
+
+  fn get_binding(&self, id: &BindingIdentifier) -> Binding<'a> {
+    Binding {
+      name: self.get_name(id.span, &id.name),
+      start: id.span.start,
+      end: id.span.end,
+      literal: None,
+    }
+  }
+
+  /// Gets the `ImportAttributes` of a `with` or `assert` clause, with byte offsets.
+  fn get_with_clause_attributes(&self, with_clause: &WithClause) -> ImportAttributes<'a> {
+    let keyword = if with_clause.attributes_keyword.name == "assert" {
+      AttributesKeyword::Assert
+    } else {
+      AttributesKeyword::With
+    };
+
+    ImportAttributes {
+      span: Span {
+        start: self.find_index_by_char(
+          with_clause.attributes_keyword.span.end as usize,
+          CURLY_BRACE_LEFT,
+          FindIndexByCharType::Next,
+        ) as u32,
+        end: with_clause.span.end,
       },
+      keyword: Some(keyword),
+      entries: with_clause
+        .with_entries
+        .iter()
+        .map(|entry| {
+          let (key, key_span) = match &entry.key {
+            ImportAttributeKey::Identifier(id) => (
+              self.get_name(id.span, &id.name),
+              Span {
+                start: id.span.start,
+                end: id.span.end,
+              },
+            ),
+            ImportAttributeKey::StringLiteral(literal) => (
+              self.get_string_literal_value(literal),
+              get_string_literal_span(literal),
+            ),
+          };
+
+          ImportAttribute {
+            key,
+            key_span,
+            value: self.get_string_literal_value(&entry.value),
+            value_span: get_string_literal_span(&entry.value),
+          }
+        })
+        .collect(),
     }
   }
 
-  fn add_import(&mut self, import: Import) {
-    self.result.imports.push(Import {
-      n: import.n,
-      s: self.byte_to_char[import.s as usize] as u32,
-      e: self.get_char_index(import.e as i32) as u32,
-      ss: self.byte_to_char[import.ss as usize] as u32,
-      se: self.get_char_index(import.se as i32) as u32,
-      a: self.get_char_index(import.a) as i32,
-      d: self.get_char_index(import.d) as i32,
-    })
+  /// Gets the `ImportAttributes` of the options object of a dynamic import, with byte offsets.
+  ///
+  /// Only entries with a string literal value are collected,
+  /// the others can not be known without evaluating the options.
+  fn get_options_attributes(&self, options: &ObjectExpression) -> ImportAttributes<'a> {
+    let mut attributes = ImportAttributes {
+      span: Span {
+        start: options.span.start,
+        end: options.span.end,
+      },
+      keyword: None,
+      entries: vec![],
+    };
+
+    // import('xx', { with: { type: 'json' } })
+    //                ^^^^  ^^^^^^^^^^^^^^^^
+    for property in &options.properties {
+      let ObjectPropertyKind::ObjectProperty(property) = property else {
+        continue;
+      };
+      let Some((key, _)) = self.get_property_key(&property.key) else {
+        continue;
+      };
+      let keyword = match key.as_ref() {
+        "with" => AttributesKeyword::With,
+        "assert" => AttributesKeyword::Assert,
+        _ => continue,
+      };
+
+      attributes.keyword = Some(keyword);
+      attributes.entries.clear();
+
+      let Expression::ObjectExpression(obj_expr) = &property.value else {
+        continue;
+      };
+
+      // import('xx', { with: { type: 'json' } })
+      //                        ^^^^  ^^^^^^
+      for property in &obj_expr.properties {
+        if let ObjectPropertyKind::ObjectProperty(property) = property {
+          if let (Some((key, key_span)), Expression::StringLiteral(value)) =
+            (self.get_property_key(&property.key), &property.value)
+          {
+            attributes.entries.push(ImportAttribute {
+              key,
+              key_span,
+              value: self.get_string_literal_value(value),
+              value_span: get_string_literal_span(value),
+            });
+          }
+        }
+      }
+    }
+
+    attributes
   }
 
-  fn add_export(&mut self, export: Export) {
-    self.result.exports.push(Export {
-      n: export.n,
-      ln: export.ln,
-      s: self.byte_to_char[export.s as usize] as u32,
-      e: self.byte_to_char[export.e as usize] as u32,
-      ls: self.get_char_index(export.ls) as i32,
-      le: self.get_char_index(export.le) as i32,
-    })
+  /// Gets the name and byte span of a non-computed identifier or string literal property key.
+  fn get_property_key(&self, key: &PropertyKey) -> Option<(Cow<'a, str>, Span)> {
+    match key {
+      PropertyKey::Identifier(id) => Some((
+        self.get_name(id.span, &id.name),
+        Span {
+          start: id.span.start,
+          end: id.span.end,
+        },
+      )),
+      PropertyKey::Expression(Expression::StringLiteral(literal)) => Some((
+        self.get_string_literal_value(literal),
+        get_string_literal_span(literal),
+      )),
+      _ => None,
+    }
   }
 
-  fn get_char_index(&self, byte_index: i32) -> usize {
-    if byte_index < 0 || byte_index >= self.source_text.len() as i32 {
-      byte_index as usize
+  /// Converts the byte offsets of a `Span` to the selected `OffsetEncoding`.
+  fn convert_span(&self, span: Span) -> Span {
+    Span {
+      start: self.get_offset(span.start),
+      end: self.get_offset(span.end),
+    }
+  }
+
+  /// Converts the byte offsets of a `Binding` to the selected `OffsetEncoding`.
+  fn convert_binding(&self, binding: Binding<'a>) -> Binding<'a> {
+    Binding {
+      start: self.get_offset(binding.start),
+      end: self.get_offset(binding.end),
+      ..binding
+    }
+  }
+
+  /// Converts a byte offset to the offset in the selected `OffsetEncoding`.
+  fn get_offset(&self, byte_index: u32) -> u32 {
+    self.offsets[byte_index as usize]
+  }
+
+  /// Like `get_offset`, but keeps negative sentinels like `-1` as is.
+  fn get_optional_offset(&self, byte_index: i32) -> i32 {
+    if byte_index < 0 {
+      byte_index
     } else {
-      self.byte_to_char[byte_index as usize]
+      self.get_offset(byte_index as u32) as i32
     }
   }
 
+  /// Gets the ASCII char at a byte offset, `None` for non-ASCII chars.
+  fn get_ascii_char(&self, byte_index: usize) -> Option<char> {
+    let byte = self.source_text.as_bytes()[byte_index];
+
+    byte.is_ascii().then_some(byte as char)
+  }
+
   fn adjust_statement_end(&mut self, se: u32) -> u32 {
-    let last_ch = self.source_text_chars[self.byte_to_char[se as usize - 1]];
+    let last_ch = self.get_ascii_char(se as usize - 1).unwrap_or_default();
 
     if last_ch == SINGLE_QUOTE
       || last_ch == QUOTE
@@ -190,12 +1976,12 @@ impl<'a> Visitor<'a> {
   }
 
   fn find_index_by_char(
-    &mut self,
+    &self,
     mut current: usize,
     ch: char,
     find_type: FindIndexByCharType,
   ) -> usize {
-    while self.source_text_chars[self.byte_to_char[current]] != ch {
+    while self.get_ascii_char(current) != Some(ch) {
       match find_type {
         FindIndexByCharType::Last => current -= 1,
         FindIndexByCharType::Next => current += 1,
@@ -206,8 +1992,8 @@ impl<'a> Visitor<'a> {
   }
 }
 
-impl<'a> Visit<'a> for Visitor<'a> {
-  fn enter_node(&mut self, kind: AstKind<'a>) {
+impl<'a, 'b> Visit<'b> for Visitor<'a> {
+  fn enter_node(&mut self, kind: AstKind<'b>) {
     match kind {
       AstKind::Program(program) => {
         self.result.facade = program.body.iter().all(|stmt| {
@@ -219,31 +2005,96 @@ impl<'a> Visit<'a> for Visitor<'a> {
             false
           }
         });
+        self.declarations = collect_declarations(program);
+        self.mutations = collect_mutations(program, &self.declarations);
+
+        let imported_names = collect_imported_names(program);
+
+        if self.references.is_some() {
+          let mut names = imported_names.clone();
+
+          names.extend(self.declarations.keys().cloned());
+          self.references = Some(collect_references(program, names));
+        }
+
+        self.usages = collect_usages(program, imported_names);
+
+        if self.folded_imports.is_some() {
+          self.folded_imports = Some(fold_dynamic_imports(program));
+        }
+      }
+      AstKind::TSModuleBlock(_) => {
+        self.ts_module_block_depth += 1;
       }
+      // declare module 'mod' { export const a: string; }
+      AstKind::ModuleDeclaration(_) | AstKind::TSImportEqualsDeclaration(_)
+        if self.ts_module_block_depth > 0 => {}
       AstKind::ModuleDeclaration(decl) => {
         self.result.has_module_syntax = true;
+        self.statement_span = decl.span();
 
         match decl {
           // import xx from 'mod' assert { type: 'json' }
           ModuleDeclaration::ImportDeclaration(decl) => {
-            let mut import = Import::default();
-
             // import xx from 'mod' assert { type: 'json' }
             //                 ^^^
-            import.n = Some(decl.source.value.to_string());
-            import.s = decl.source.span.start + 1;
-            import.e = decl.source.span.end - 1;
-            import.ss = decl.span.start;
-            import.se = self.adjust_statement_end(decl.span.end);
+            let mut import = Import {
+              n: Some(self.get_string_literal_value(&decl.source)),
+              literal: Some(self.get_quoted_string(&decl.source)),
+              s: decl.source.span.start + 1,
+              e: decl.source.span.end - 1,
+              ss: decl.span.start,
+              se: self.adjust_statement_end(decl.span.end),
+              ..Default::default()
+            };
+
+            let is_type = decl.import_kind.is_type();
+
+            // import xx, { yy as zz } from 'mod'
+            //        ^^    ^^    ^^
+            if let Some(specifiers) = &decl.specifiers {
+              import.bindings = specifiers
+                .iter()
+                .map(|specifier| match specifier {
+                  ImportDeclarationSpecifier::ImportSpecifier(specifier) => ImportBinding {
+                    kind: ImportBindingKind::Named,
+                    imported: Some(self.get_module_export_binding(&specifier.imported)),
+                    local: self.get_binding(&specifier.local),
+                    is_type: is_type || specifier.import_kind.is_type(),
+                    references: self.get_references(&specifier.local.name),
+                  },
+                  ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => ImportBinding {
+                    kind: ImportBindingKind::Default,
+                    imported: None,
+                    local: self.get_binding(&specifier.local),
+                    is_type,
+                    references: self.get_references(&specifier.local.name),
+                  },
+                  ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    ImportBinding {
+                      kind: ImportBindingKind::Namespace,
+                      imported: None,
+                      local: self.get_binding(&specifier.local),
+                      is_type,
+                      references: self.get_references(&specifier.local.name),
+                    }
+                  }
+                })
+                .collect();
+            }
+
+            // import 'mod'
+            // import {} from 'mod'
+            import.is_side_effect_only = import.bindings.is_empty() && !is_type;
+
+            // import { type yy } from 'mod'
+            //          ^^^^
+            import.is_type = is_type || is_all_type(&import.bindings);
 
             // import xx from 'mod' assert { type: 'json' }
-            //                             ^
-            if let Some(attr) = &decl.with_clause {
-              import.a = self.find_index_by_char(
-                attr.attributes_keyword.span.end as usize,
-                CURLY_BRACE_LEFT,
-                FindIndexByCharType::Next,
-              ) as i32;
+            //                             ^^^^^^^^^^^^^^^^
+            if let Some(with_clause) = &decl.with_clause {
+              import.attributes = Some(self.get_with_clause_attributes(with_clause));
             }
 
             self.add_import(import);
@@ -252,54 +2103,134 @@ impl<'a> Visit<'a> for Visitor<'a> {
           ModuleDeclaration::ExportDefaultDeclaration(decl) => {
             self.result.facade = false;
 
-            let mut export = Export::default();
-
             // export default function foo() {}
             //        ^^^^^^^
-            export.n = Some(decl.exported.name().to_string());
-            export.s = decl.exported.span().start;
-            export.e = decl.exported.span().end;
+            let mut export = Export {
+              n: Some(self.get_module_export_name(&decl.exported)),
+              s: decl.exported.span().start,
+              e: decl.exported.span().end,
+              ..Default::default()
+            };
 
             // export default function foo() {}
             //                         ^^^
             match &decl.declaration {
               ExportDefaultDeclarationKind::FunctionDeclaration(fn_decl) => {
+                export.kind = Some(ExportKind::Function);
+                export.is_type = fn_decl.is_typescript_syntax();
+
                 if let Some(id) = &fn_decl.id {
-                  export.ln = Some(id.name.to_string());
+                  export.ln = Some(self.get_name(id.span, &id.name));
                   export.ls = id.span.start as i32;
                   export.le = id.span.end as i32;
+                  export.default_kind = Some(DefaultExportKind::Function);
+                } else {
+                  export.default_kind = Some(DefaultExportKind::AnonymousFunction);
                 }
               }
               ExportDefaultDeclarationKind::ClassDeclaration(class_decl) => {
+                export.kind = Some(ExportKind::Class);
+                export.is_type = class_decl.is_declare();
+
                 if let Some(id) = &class_decl.id {
-                  export.ln = Some(id.name.to_string());
+                  export.ln = Some(self.get_name(id.span, &id.name));
                   export.ls = id.span.start as i32;
                   export.le = id.span.end as i32;
+                  export.default_kind = Some(DefaultExportKind::Class);
+                } else {
+                  export.default_kind = Some(DefaultExportKind::AnonymousClass);
                 }
               }
-              _ => (),
+              // export default interface Foo {}
+              //                          ^^^
+              ExportDefaultDeclarationKind::TSInterfaceDeclaration(interface_decl) => {
+                export.local = Some(self.get_binding(&interface_decl.id));
+                export.default_kind = Some(DefaultExportKind::Interface);
+                export.kind = Some(ExportKind::Interface);
+                export.is_type = true;
+              }
+              // export default foo;
+              //                ^^^
+              ExportDefaultDeclarationKind::Expression(expr) => {
+                export.default_kind = Some(match expr.without_parenthesized() {
+                  Expression::Identifier(id) => {
+                    export.local = Some(Binding {
+                      name: self.get_name(id.span, &id.name),
+                      start: id.span.start,
+                      end: id.span.end,
+                      literal: None,
+                    });
+                    self.classify_export(&mut export, &id.name);
+
+                    DefaultExportKind::Expression
+                  }
+                  Expression::FunctionExpression(_) | Expression::ArrowExpression(_) => {
+                    DefaultExportKind::AnonymousFunction
+                  }
+                  Expression::ClassExpression(_) => DefaultExportKind::AnonymousClass,
+                  _ => DefaultExportKind::Expression,
+                });
+              }
+              // `export default enum Foo {}` is invalid TypeScript
+              ExportDefaultDeclarationKind::TSEnumDeclaration(_) => {
+                export.default_kind = Some(DefaultExportKind::Expression);
+              }
             }
 
             self.add_export(export);
           }
           // export * as all from 'xxx'
           ModuleDeclaration::ExportAllDeclaration(decl) => {
-            let mut import = Import::default();
-
-            import.n = Some(decl.source.value.to_string());
-            import.ss = decl.span.start;
-            import.se = self.adjust_statement_end(decl.span.end);
-            import.s = decl.source.span.start + 1;
-            import.e = decl.source.span.end - 1;
+            let mut import = Import {
+              kind: ImportKind::ExportStar,
+              is_type: decl.export_kind.is_type(),
+              n: Some(self.get_string_literal_value(&decl.source)),
+              literal: Some(self.get_quoted_string(&decl.source)),
+              ss: decl.span.start,
+              se: self.adjust_statement_end(decl.span.end),
+              s: decl.source.span.start + 1,
+              e: decl.source.span.end - 1,
+              ..Default::default()
+            };
 
-            self.add_import(import);
+            // export * from 'mod' with { type: 'json' }
+            //                          ^^^^^^^^^^^^^^^^
+            if let Some(with_clause) = &decl.with_clause {
+              import.attributes = Some(self.get_with_clause_attributes(with_clause));
+            }
 
+            // export * as all from 'xxx'
+            //             ^^^
             if let Some(exported) = &decl.exported {
-              let mut export = Export::default();
+              import.bindings.push(ImportBinding {
+                kind: ImportBindingKind::Namespace,
+                imported: None,
+                local: self.get_module_export_binding(exported),
+                is_type: import.is_type,
+                references: vec![],
+              });
+            }
+
+            let is_type = import.is_type;
+            let specifier = import.n.clone();
+            let import_index = self.add_import(import);
 
-              export.n = Some(exported.name().to_string());
-              export.s = exported.span().start;
-              export.e = exported.span().end;
+            if let Some(exported) = &decl.exported {
+              let export = Export {
+                n: Some(self.get_module_export_name(exported)),
+                literal: self.get_module_export_literal(exported),
+                s: exported.span().start,
+                e: exported.span().end,
+                is_type,
+                origin: import_index
+                  .zip(specifier)
+                  .map(|(import_index, specifier)| ExportOrigin {
+                    specifier,
+                    imported: None,
+                    import_index,
+                  }),
+                ..Default::default()
+              };
 
               self.add_export(export);
             }
@@ -309,6 +2240,8 @@ impl<'a> Visit<'a> for Visitor<'a> {
           // export { b as c };
           ModuleDeclaration::ExportNamedDeclaration(decl) => {
             let mut has_import = false;
+            let mut import_index = None;
+            let is_type = decl.export_kind.is_type();
 
             // export { xxx } from 'mod';
             //                      ^^^
@@ -317,13 +2250,33 @@ impl<'a> Visit<'a> for Visitor<'a> {
 
               let mut import = Import::default();
 
-              import.n = Some(source.value.to_string());
+              import.kind = ImportKind::ReExport;
+              import.n = Some(self.get_string_literal_value(source));
+              import.literal = Some(self.get_quoted_string(source));
               import.ss = decl.span.start;
               import.se = self.adjust_statement_end(decl.span.end);
               import.s = source.span.start + 1;
               import.e = source.span.end - 1;
 
-              self.add_import(import);
+              // export { xxx as yyy } from 'mod';
+              //          ^^^    ^^^
+              import.bindings = decl
+                .specifiers
+                .iter()
+                .map(|specifier| ImportBinding {
+                  kind: ImportBindingKind::Named,
+                  imported: Some(self.get_module_export_binding(&specifier.local)),
+                  local: self.get_module_export_binding(&specifier.exported),
+                  is_type: is_type || specifier.export_kind.is_type(),
+                  references: vec![],
+                })
+                .collect();
+
+              // export { type xxx } from 'mod';
+              //          ^^^^
+              import.is_type = is_type || is_all_type(&import.bindings);
+
+              import_index = self.add_import(import);
             }
 
             // export const a = 1;
@@ -337,40 +2290,63 @@ impl<'a> Visit<'a> for Visitor<'a> {
             if let Some(inner_decl) = &decl.declaration {
               self.result.facade = false;
 
+              let info = DeclarationInfo::new(inner_decl);
+
               match inner_decl {
                 // export const a = 1;
                 //              ^
                 Declaration::VariableDeclaration(var_decl) => {
-                  if var_decl.declarations.len() != 0 {
-                    if let BindingPatternKind::BindingIdentifier(id) =
-                      &var_decl.declarations[0].id.kind
-                    {
-                      id.add_to_exports(self);
-                    }
+                  for declarator in &var_decl.declarations {
+                    declarator.id.add_to_exports(self, info);
                   }
                 }
                 // export function foo() {}
                 //                 ^^^
                 Declaration::FunctionDeclaration(fn_decl) => {
                   if let Some(id) = &fn_decl.id {
-                    id.add_to_exports(self);
+                    id.add_to_exports(self, info);
                   }
                 }
                 // export class Bar {}
                 //              ^^^
                 Declaration::ClassDeclaration(class_decl) => {
                   if let Some(id) = &class_decl.id {
-                    id.add_to_exports(self);
+                    id.add_to_exports(self, info);
                   }
                 }
                 Declaration::TSEnumDeclaration(enum_decl) => {
-                  enum_decl.id.add_to_exports(self);
+                  enum_decl.id.add_to_exports(self, info);
                 }
                 Declaration::TSInterfaceDeclaration(interface_decl) => {
-                  interface_decl.id.add_to_exports(self);
+                  interface_decl.id.add_to_exports(self, info);
                 }
                 Declaration::TSTypeAliasDeclaration(type_alias_decl) => {
-                  type_alias_decl.id.add_to_exports(self);
+                  type_alias_decl.id.add_to_exports(self, info);
+                }
+                // export namespace N {}
+                //                  ^
+                // ----------------------------
+                // export declare module M {}
+                //                       ^
+                Declaration::TSModuleDeclaration(module_decl) => {
+                  if let TSModuleDeclarationName::Identifier(id) = &module_decl.id {
+                    self.add_export(Export {
+                      n: Some(self.get_name(id.span, &id.name)),
+                      s: id.span.start,
+                      e: id.span.end,
+                      ln: Some(self.get_name(id.span, &id.name)),
+                      ls: id.span.start as i32,
+                      le: id.span.end as i32,
+                      kind: Some(info.kind),
+                      is_type: info.is_type,
+                      ..Default::default()
+                    });
+                  }
+                }
+                // export import A = B.C;
+                //               ^
+                Declaration::TSImportEqualsDeclaration(import_equals_decl) => {
+                  import_equals_decl.id.add_to_exports(self, info);
                 }
                 _ => (),
               }
@@ -379,24 +2355,100 @@ impl<'a> Visit<'a> for Visitor<'a> {
             // export { c as d }
             //          ^    ^
             decl.specifiers.iter().for_each(|specifier| {
-              let mut export = Export::default();
-
-              export.n = Some(specifier.exported.name().to_string());
-              export.s = specifier.exported.span().start;
-              export.e = specifier.exported.span().end;
+              let mut export = Export {
+                n: Some(self.get_module_export_name(&specifier.exported)),
+                literal: self.get_module_export_literal(&specifier.exported),
+                s: specifier.exported.span().start,
+                e: specifier.exported.span().end,
+                ..Default::default()
+              };
 
               if !has_import {
-                export.ln = Some(specifier.local.name().to_string());
+                export.ln = Some(self.get_module_export_name(&specifier.local));
                 export.ls = specifier.local.span().start as i32;
                 export.le = specifier.local.span().end as i32;
+
+                if let ModuleExportName::Identifier(id) = &specifier.local {
+                  self.classify_export(&mut export, &id.name);
+                }
+              }
+
+              // export { c as d } from 'mod';
+              //          ^          ^^^
+              if let (Some(import_index), Some(source)) = (import_index, &decl.source) {
+                export.origin = Some(ExportOrigin {
+                  specifier: self.get_string_literal_value(source),
+                  imported: Some(self.get_module_export_name(&specifier.local)),
+                  import_index,
+                });
+              }
+
+              // export { type c as d }
+              //          ^^^^
+              if is_type || specifier.export_kind.is_type() {
+                export.is_type = true;
               }
 
               self.add_export(export);
             })
           }
+          // export = foo;
+          //          ^^^
+          ModuleDeclaration::TSExportAssignment(decl) => {
+            self.result.facade = false;
+
+            let mut export = Export {
+              is_export_assignment: true,
+              s: decl.expression.span().start,
+              e: decl.expression.span().end,
+              ..Default::default()
+            };
+
+            if let Expression::Identifier(id) = &decl.expression {
+              export.ln = Some(self.get_name(id.span, &id.name));
+              export.ls = id.span.start as i32;
+              export.le = id.span.end as i32;
+              self.classify_export(&mut export, &id.name);
+            }
+
+            self.add_export(export);
+          }
           _ => (),
         };
       }
+      // import fs = require('fs');
+      AstKind::TSImportEqualsDeclaration(decl) => {
+        if let TSModuleReference::ExternalModuleReference(reference) = &*decl.module_reference {
+          self.result.has_module_syntax = true;
+          self.statement_span = decl.span;
+
+          // import fs = require('fs');
+          //                      ^^
+          let mut import = Import {
+            kind: ImportKind::ImportEquals,
+            is_type: decl.import_kind.is_type(),
+            n: Some(self.get_string_literal_value(&reference.expression)),
+            literal: Some(self.get_quoted_string(&reference.expression)),
+            s: reference.expression.span.start + 1,
+            e: reference.expression.span.end - 1,
+            ss: decl.span.start,
+            se: self.adjust_statement_end(decl.span.end),
+            ..Default::default()
+          };
+
+          // import fs = require('fs');
+          //        ^^
+          import.bindings.push(ImportBinding {
+            kind: ImportBindingKind::Namespace,
+            imported: None,
+            local: self.get_binding(&decl.id),
+            is_type: import.is_type,
+            references: self.get_references(&decl.id.name),
+          });
+
+          self.add_import(import);
+        }
+      }
       // import('xx', { assert: { type: 'json' } })
       AstKind::ImportExpression(expr) => {
         let mut import = Import::default();
@@ -408,16 +2460,34 @@ impl<'a> Visit<'a> for Visitor<'a> {
 
         // import('xx', { assert: { type: 'json' } })
         //       ^
-        import.d =
-          self.find_index_by_char(import.s as usize, BRACKET_LEFT, FindIndexByCharType::Last)
-            as i32;
+        import.kind = ImportKind::Dynamic {
+          paren: self.find_index_by_char(import.s as usize, BRACKET_LEFT, FindIndexByCharType::Last)
+            as u32,
+        };
 
         // import('xx', { assert: { type: 'json' } })
         //         ^^
         if let Expression::StringLiteral(s) = &expr.source {
-          import.n = Some(s.value.to_string());
+          import.n = Some(self.get_string_literal_value(s));
+          import.literal = Some(self.get_quoted_string(s));
         } else {
           self.result.facade = false;
+
+          // const base = './icons/'; import(base + 'x.js')
+          //                                 ^^^^^^^^^^^^^
+          let folded = self
+            .folded_imports
+            .as_ref()
+            .and_then(|folded| folded.get(&expr.span.start));
+
+          if let Some(value) = folded {
+            import.n = Some(Cow::Owned(value.clone()));
+            import.is_derived = true;
+          } else {
+            // import(`./locales/${lang}.json`)
+            //        ^^^^^^^^^^^^^^^^^^^^^^^^^
+            import.pattern = self.get_dynamic_import_pattern(&expr.source);
+          }
         }
 
         if expr.arguments.len() != 0 {
@@ -425,28 +2495,86 @@ impl<'a> Visit<'a> for Visitor<'a> {
 
           if let Expression::ObjectExpression(obj_expr) = &expr.arguments[0] {
             // import('xx', { assert: { type: 'json' } })
-            //              ^
-            import.a = obj_expr.span.start as i32;
+            //              ^^^^^^^^^^^^^^^^^^^^^^^^^^
+            import.attributes = Some(self.get_options_attributes(obj_expr));
           }
         }
 
         self.add_import(import);
       }
+      // import.meta.glob('./modules/*.ts')
+      AstKind::CallExpression(call) => {
+        if let Some((start, glob)) = self.get_import_meta_glob(call) {
+          self.import_meta_globs.insert(start, glob);
+        }
+
+        // navigator.serviceWorker.register('./sw.js')
+        for entry_point in self.get_call_entry_points(call) {
+          self.add_entry_point(entry_point);
+        }
+      }
+      // new URL('./logo.png', import.meta.url)
+      AstKind::NewExpression(expr) => {
+        // the `new URL()` of `new Worker(new URL('./worker.js', import.meta.url))` is already an entry point,
+        // as the worker is entered before its arguments
+        if self.asset_urls.is_some() {
+          if let Some((start, import)) = self.get_asset_url(expr) {
+            if !self.entry_point_urls.contains(&start) {
+              self.asset_urls.as_mut().unwrap().insert(start, import);
+            }
+          }
+        }
+
+        // new Worker('./worker.js', { type: 'module' })
+        let kind = match &expr.callee {
+          Expression::Identifier(id) if id.name == "Worker" => Some(EntryPointKind::Worker),
+          Expression::Identifier(id) if id.name == "SharedWorker" => {
+            Some(EntryPointKind::SharedWorker)
+          }
+          _ => None,
+        };
+
+        if let Some(entry_point) =
+          kind.and_then(|kind| self.get_entry_point(kind, &expr.arguments, expr.span))
+        {
+          self.add_entry_point(entry_point);
+        }
+      }
       // import.meta
       AstKind::MetaProperty(meta) => {
         self.result.has_module_syntax = true;
 
-        let mut import = Import::default();
+        let asset_url = self
+          .asset_urls
+          .as_mut()
+          .and_then(|asset_urls| asset_urls.remove(&meta.span.start));
+        let mut import = asset_url.unwrap_or_else(|| Import {
+          kind: ImportKind::ImportMeta,
+          ss: meta.span.start,
+          se: meta.span.end,
+          s: meta.span.start,
+          e: meta.span.end,
+          ..Default::default()
+        });
 
-        import.d = -2;
-        import.ss = meta.span.start;
-        import.se = meta.span.end;
-        import.s = meta.span.start;
-        import.e = meta.span.end;
+        // import.meta.glob('./modules/*.ts')
+        // ^^^^^^^^^^^
+        import.glob = self.import_meta_globs.remove(&meta.span.start);
 
         self.add_import(import);
       }
       _ => (),
     };
   }
+
+  fn leave_node(&mut self, kind: AstKind<'b>) {
+    match kind {
+      AstKind::Program(_) => {
+        self.resolve_export_origins();
+        self.resolve_export_dependencies();
+      }
+      AstKind::TSModuleBlock(_) => self.ts_module_block_depth -= 1,
+      _ => (),
+    }
+  }
 }
@@ -0,0 +1,81 @@
+use es_module_lexer::{parse, AttributesKeyword, ImportAttribute, Span};
+use std::borrow::Cow;
+
+fn attribute(key: (&str, u32), value: (&str, u32)) -> ImportAttribute<'static> {
+  ImportAttribute {
+    key: Cow::Owned(key.0.to_string()),
+    key_span: Span {
+      start: key.1,
+      end: key.1 + key.0.len() as u32,
+    },
+    value: Cow::Owned(value.0.to_string()),
+    value_span: Span {
+      start: value.1,
+      end: value.1 + value.0.len() as u32,
+    },
+  }
+}
+
+#[test]
+fn test_static_attributes() {
+  let source_text = r#"import a from './a.json' with { type: 'json', 'x-y': "z" };
+import b from './b.json' assert { type: 'json' };
+export * from './c.json' with { type: 'json' };
+import d from './d.js';
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let imports = &result.imports;
+
+  let attributes = imports[0].attributes.as_ref().unwrap();
+  assert_eq!(attributes.keyword, Some(AttributesKeyword::With));
+  assert_eq!(
+    attributes.entries,
+    [
+      attribute(("type", 32), ("json", 39)),
+      attribute(("x-y", 47), ("z", 54)),
+    ]
+  );
+
+  let attributes = imports[1].attributes.as_ref().unwrap();
+  assert_eq!(attributes.keyword, Some(AttributesKeyword::Assert));
+  assert_eq!(attributes.entries, [attribute(("type", 94), ("json", 101))]);
+
+  let attributes = imports[2].attributes.as_ref().unwrap();
+  assert_eq!(attributes.keyword, Some(AttributesKeyword::With));
+  assert_eq!(&source_text[imports[2].a as usize..][..1], "{");
+  assert_eq!(
+    attributes.entries,
+    [attribute(("type", 142), ("json", 149))]
+  );
+
+  assert!(imports[3].attributes.is_none());
+}
+
+#[test]
+fn test_dynamic_attributes() {
+  let source_text = r#"import('./a.json', { with: { type: 'json' } });
+import('./b.json', { assert: { 'type': 'json', other: x } });
+import('./c.js', options);
+import('./d.js', { signal });
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let imports = &result.imports;
+
+  let attributes = imports[0].attributes.as_ref().unwrap();
+  assert_eq!(attributes.span, Span { start: 19, end: 45 });
+  assert_eq!(imports[0].a, 19);
+  assert_eq!(attributes.keyword, Some(AttributesKeyword::With));
+  assert_eq!(attributes.entries, [attribute(("type", 29), ("json", 36))]);
+
+  let attributes = imports[1].attributes.as_ref().unwrap();
+  assert_eq!(attributes.keyword, Some(AttributesKeyword::Assert));
+  assert_eq!(attributes.entries, [attribute(("type", 80), ("json", 88))]);
+
+  assert!(imports[2].attributes.is_none());
+
+  let attributes = imports[3].attributes.as_ref().unwrap();
+  assert_eq!(attributes.keyword, None);
+  assert!(attributes.entries.is_empty());
+}
@@ -0,0 +1,194 @@
+use es_module_lexer::{parse, Binding, ImportBinding, ImportBindingKind, Quote, QuotedString};
+use std::borrow::Cow;
+
+fn binding(name: &str, start: u32, end: u32) -> Binding {
+  Binding {
+    name: Cow::Borrowed(name),
+    start,
+    end,
+    literal: None,
+  }
+}
+
+fn string_binding(name: &str, start: u32, end: u32) -> Binding {
+  Binding {
+    literal: Some(QuotedString {
+      raw: Cow::Borrowed(name),
+      value: Cow::Borrowed(name),
+      quote: Quote::Single,
+      has_escapes: false,
+    }),
+    ..binding(name, start, end)
+  }
+}
+
+#[test]
+fn test_import_bindings() {
+  let source_text = r#"import a, { b as c, 'd-e' as f, g } from 'mod';
+import * as ns from 'ns';
+import 'side-effect';
+import {} from 'empty';
+import('dynamic');
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let imports = &result.imports;
+
+  assert_eq!(
+    imports[0].bindings,
+    [
+      ImportBinding {
+        kind: ImportBindingKind::Default,
+        imported: None,
+        local: binding("a", 7, 8),
+        is_type: false,
+        references: vec![],
+      },
+      ImportBinding {
+        kind: ImportBindingKind::Named,
+        imported: Some(binding("b", 12, 13)),
+        local: binding("c", 17, 18),
+        is_type: false,
+        references: vec![],
+      },
+      ImportBinding {
+        kind: ImportBindingKind::Named,
+        imported: Some(string_binding("d-e", 21, 24)),
+        local: binding("f", 29, 30),
+        is_type: false,
+        references: vec![],
+      },
+      ImportBinding {
+        kind: ImportBindingKind::Named,
+        imported: Some(binding("g", 32, 33)),
+        local: binding("g", 32, 33),
+        is_type: false,
+        references: vec![],
+      },
+    ]
+  );
+  assert_eq!(
+    imports[1].bindings,
+    [ImportBinding {
+      kind: ImportBindingKind::Namespace,
+      imported: None,
+      local: binding("ns", 60, 62),
+      is_type: false,
+      references: vec![],
+    }]
+  );
+  assert!(imports[2].bindings.is_empty());
+  assert!(imports[3].bindings.is_empty());
+  assert!(imports[4].bindings.is_empty());
+  assert_eq!(
+    imports
+      .iter()
+      .map(|x| x.is_side_effect_only)
+      .collect::<Vec<_>>(),
+    [false, false, true, true, false]
+  );
+}
+
+#[test]
+fn test_binding_references() {
+  let source_text = r#"import a, { b, type C } from 'mod';
+import * as ns from 'ns';
+import fs = require('fs');
+const x: C = a(b);
+function f(a) {
+  return a + ns.y;
+}
+let y: typeof b = <ns.Comp />;
+class D implements ns.I {}
+export { a, type C };
+"#;
+
+  let result = parse(source_text, "index.tsx").unwrap();
+  let references = |index: usize| {
+    result.imports[index]
+      .bindings
+      .iter()
+      .map(|binding| {
+        let references = binding
+          .references
+          .iter()
+          .map(|reference| (reference.span.start, reference.is_type))
+          .collect::<Vec<_>>();
+
+        (binding.local.name.as_ref(), references)
+      })
+      .collect::<Vec<_>>()
+  };
+
+  assert_eq!(
+    references(0),
+    [
+      ("a", vec![(102, false), (212, false)]),
+      ("b", vec![(104, false), (159, true)]),
+      ("C", vec![(98, true), (220, true)]),
+    ]
+  );
+  assert_eq!(
+    references(1),
+    [("ns", vec![(137, false), (164, false), (195, true)])]
+  );
+  assert_eq!(references(2), [("fs", vec![])]);
+}
+
+#[test]
+fn test_binding_references_order() {
+  let source_text = r#"import A from 'a';
+class C extends A implements A {}
+"#;
+
+  let result = parse(source_text, "index.ts").unwrap();
+  let references = result.imports[0].bindings[0]
+    .references
+    .iter()
+    .map(|reference| (reference.span.start, reference.is_type))
+    .collect::<Vec<_>>();
+
+  assert_eq!(references, [(35, false), (48, true)]);
+}
+
+#[test]
+fn test_re_export_bindings() {
+  let source_text = r#"export { a, b as 'c-d' } from 'mod';
+export * as ns from 'ns';
+export * from 'all';
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let imports = &result.imports;
+
+  assert_eq!(
+    imports[0].bindings,
+    [
+      ImportBinding {
+        kind: ImportBindingKind::Named,
+        imported: Some(binding("a", 9, 10)),
+        local: binding("a", 9, 10),
+        is_type: false,
+        references: vec![],
+      },
+      ImportBinding {
+        kind: ImportBindingKind::Named,
+        imported: Some(binding("b", 12, 13)),
+        local: string_binding("c-d", 18, 21),
+        is_type: false,
+        references: vec![],
+      },
+    ]
+  );
+  assert_eq!(
+    imports[1].bindings,
+    [ImportBinding {
+      kind: ImportBindingKind::Namespace,
+      imported: None,
+      local: binding("ns", 49, 51),
+      is_type: false,
+      references: vec![],
+    }]
+  );
+  assert!(imports[2].bindings.is_empty());
+}
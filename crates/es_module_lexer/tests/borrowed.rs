@@ -0,0 +1,91 @@
+use es_module_lexer::{parse, ParseResult, Quote, QuotedString};
+use std::borrow::Cow;
+
+#[test]
+fn test_borrowed_names() {
+  let source_text = r#"
+import { a } from 'mod';
+import b from './\x62';
+export { a as 'external name', a as c };
+export const \u0064 = 1;
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+
+  assert!(matches!(result.imports[0].n, Some(Cow::Borrowed("mod"))));
+  assert!(matches!(&result.imports[1].n, Some(Cow::Owned(n)) if n == "./b"));
+
+  assert!(matches!(
+    result.exports[0].n,
+    Some(Cow::Borrowed("external name"))
+  ));
+  assert!(matches!(result.exports[0].ln, Some(Cow::Borrowed("a"))));
+  assert!(matches!(result.exports[1].n, Some(Cow::Borrowed("c"))));
+  assert!(matches!(&result.exports[2].n, Some(Cow::Owned(n)) if n == "d"));
+}
+
+#[test]
+fn test_literals() {
+  let source_text = r#"import a from "./\x61";
+import('b');
+export { a as 'c\u002Dd' };
+export { a as e };
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+
+  assert_eq!(
+    result.imports[0].literal,
+    Some(QuotedString {
+      raw: Cow::Borrowed("./\\x61"),
+      value: Cow::Borrowed("./a"),
+      quote: Quote::Double,
+      has_escapes: true,
+    })
+  );
+  assert_eq!(
+    &source_text[result.imports[0].s as usize..result.imports[0].e as usize],
+    "./\\x61"
+  );
+  assert_eq!(
+    result.imports[1].literal,
+    Some(QuotedString {
+      raw: Cow::Borrowed("b"),
+      value: Cow::Borrowed("b"),
+      quote: Quote::Single,
+      has_escapes: false,
+    })
+  );
+  // dynamic imports include the quotes in `s..e`
+  let import = &result.imports[1];
+
+  assert_eq!(&source_text[import.s as usize..import.e as usize], "'b'");
+  assert_eq!(
+    &source_text[import.s as usize + 1..import.e as usize - 1],
+    import.literal.as_ref().unwrap().raw
+  );
+  assert_eq!(
+    result.exports[0].literal,
+    Some(QuotedString {
+      raw: Cow::Borrowed("c\\u002Dd"),
+      value: Cow::Borrowed("c-d"),
+      quote: Quote::Single,
+      has_escapes: true,
+    })
+  );
+  assert_eq!(result.exports[1].literal, None);
+}
+
+#[test]
+fn test_into_owned() {
+  let result: ParseResult<'static> = {
+    let source_text = String::from("import { a } from 'mod';\nexport { a };");
+
+    parse(&source_text, "index.js").unwrap().into_owned()
+  };
+
+  assert_eq!(result.imports[0].n.as_deref(), Some("mod"));
+  assert!(matches!(result.imports[0].n, Some(Cow::Owned(_))));
+  assert_eq!(result.exports[0].n.as_deref(), Some("a"));
+  assert_eq!(result.exports[0].ln.as_deref(), Some("a"));
+}
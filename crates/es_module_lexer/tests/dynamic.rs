@@ -0,0 +1,234 @@
+use es_module_lexer::{
+  parse, parse_with_options, ImportMetaGlob, ImportMetaGlobKind, ImportMetaGlobOptions,
+  ParseOptions, Span,
+};
+
+#[test]
+fn test_dynamic_import_patterns() {
+  let source_text = r#"import(`./locales/${lang}.json`);
+import('./pages/' + name + '.js');
+import(`./${a}${b}/` + c);
+import(`./static.js`);
+import('./a.js');
+import(a + b + '.js');
+import(foo);
+import(`${foo}`);
+import('' + foo + `${bar}`);
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let imports = &result.imports;
+  let pattern = |index: usize| {
+    let pattern = imports[index].pattern.as_ref()?;
+    let expressions = pattern
+      .expressions
+      .iter()
+      .map(|span| &source_text[span.start as usize..span.end as usize])
+      .collect::<Vec<_>>();
+
+    Some((pattern.quasis.clone(), expressions, pattern.glob.as_str()))
+  };
+
+  assert_eq!(
+    pattern(0),
+    Some((
+      vec!["./locales/".into(), ".json".into()],
+      vec!["lang"],
+      "./locales/*.json"
+    ))
+  );
+  assert_eq!(
+    pattern(1),
+    Some((
+      vec!["./pages/".into(), ".js".into()],
+      vec!["name"],
+      "./pages/*.js"
+    ))
+  );
+  assert_eq!(
+    pattern(2),
+    Some((
+      vec!["./".into(), "".into(), "/".into(), "".into()],
+      vec!["a", "b", "c"],
+      "./*/*"
+    ))
+  );
+  assert_eq!(
+    pattern(3),
+    Some((vec!["./static.js".into()], vec![], "./static.js"))
+  );
+  assert_eq!(pattern(4), None);
+  assert_eq!(pattern(5), None);
+  assert_eq!(pattern(6), None);
+  assert_eq!(pattern(7), None);
+  assert_eq!(pattern(8), None);
+  assert_eq!(imports[0].n, None);
+  assert_eq!(
+    imports[0].pattern.as_ref().unwrap().expressions,
+    [Span { start: 20, end: 24 }]
+  );
+}
+
+#[test]
+fn test_fold_constants() {
+  let source_text = r#"const base = './icons/';
+export const BASE = `${base}v2`;
+let mutable = './m/';
+import(base + 'x.js');
+import(`${BASE}/a.js`);
+import(`./static.js`);
+import(mutable + 'y.js');
+import(base + name);
+function load(base) {
+  return import(base + 'z.js');
+}
+"#;
+
+  let options = ParseOptions {
+    fold_constants: true,
+    ..Default::default()
+  };
+  let result = parse_with_options(source_text, "index.js", options).unwrap();
+  let folded = result
+    .imports
+    .iter()
+    .map(|import| (import.n.as_deref(), import.is_derived))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    folded,
+    [
+      (Some("./icons/x.js"), true),
+      (Some("./icons/v2/a.js"), true),
+      (Some("./static.js"), true),
+      (None, false),
+      (None, false),
+      (None, false),
+    ]
+  );
+  assert_eq!(result.imports[0].pattern, None);
+  assert_eq!(
+    result.imports[3].pattern.as_ref().map(|x| x.glob.as_str()),
+    Some("*y.js")
+  );
+  assert_eq!(
+    &source_text[result.imports[0].s as usize..result.imports[0].e as usize],
+    "base + 'x.js'"
+  );
+
+  let result = parse(source_text, "index.js").unwrap();
+
+  assert!(result.imports.iter().all(|import| !import.is_derived));
+}
+
+#[test]
+fn test_import_meta_glob() {
+  let source_text = r#"const modules = import.meta.glob(['./modules/*.ts', '!**/*.test.ts'], {
+  eager: true,
+  import: 'setup',
+  query: '?raw',
+});
+const docs = import.meta.glob(`./docs/*.md`, { query: { raw: true, lang: 'md' }, base: './src' });
+const images = import.meta.globEager('./img/*.png', { as: 'url', exhaustive: false });
+const env = import.meta.glob(import.meta.env.DEV ? './dev/*.ts' : './prod/*.ts', { eager: flag });
+console.log(import.meta.url, import.meta.glob);
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let globs = result
+    .imports
+    .iter()
+    .map(|import| import.glob.clone())
+    .collect::<Vec<_>>();
+  let options = |span: Span| ImportMetaGlobOptions {
+    span,
+    eager: None,
+    import: None,
+    query: None,
+    exhaustive: None,
+    base: None,
+  };
+
+  assert_eq!(globs.len(), 7);
+  assert_eq!(
+    globs[0],
+    Some(ImportMetaGlob {
+      kind: ImportMetaGlobKind::Glob,
+      patterns: vec!["./modules/*.ts".into(), "!**/*.test.ts".into()],
+      options: Some(ImportMetaGlobOptions {
+        eager: Some(true),
+        import: Some("setup".into()),
+        query: Some("?raw".into()),
+        ..options(Span {
+          start: 70,
+          end: 124
+        })
+      }),
+      span: Span {
+        start: 16,
+        end: 125
+      },
+    })
+  );
+  assert_eq!(
+    globs[1],
+    Some(ImportMetaGlob {
+      kind: ImportMetaGlobKind::Glob,
+      patterns: vec!["./docs/*.md".into()],
+      options: Some(ImportMetaGlobOptions {
+        query: Some("?raw=true&lang=md".into()),
+        base: Some("./src".into()),
+        ..options(Span {
+          start: 172,
+          end: 223
+        })
+      }),
+      span: Span {
+        start: 140,
+        end: 224
+      },
+    })
+  );
+  assert_eq!(
+    globs[2],
+    Some(ImportMetaGlob {
+      kind: ImportMetaGlobKind::GlobEager,
+      patterns: vec!["./img/*.png".into()],
+      options: Some(ImportMetaGlobOptions {
+        query: Some("?url".into()),
+        exhaustive: Some(false),
+        ..options(Span {
+          start: 278,
+          end: 310
+        })
+      }),
+      span: Span {
+        start: 241,
+        end: 311
+      },
+    })
+  );
+  // the condition has its own `import.meta`
+  assert_eq!(globs[3], None);
+  assert_eq!(
+    globs[4],
+    Some(ImportMetaGlob {
+      kind: ImportMetaGlobKind::Glob,
+      patterns: vec![],
+      options: Some(options(Span {
+        start: 394,
+        end: 409
+      })),
+      span: Span {
+        start: 325,
+        end: 410
+      },
+    })
+  );
+  assert_eq!(globs[5], None);
+  assert_eq!(globs[6], None);
+  assert!(result
+    .imports
+    .iter()
+    .all(|import| import.d == -2 && import.n.is_none()));
+}
@@ -0,0 +1,132 @@
+use es_module_lexer::{parse, parse_with_options, EntryPointKind, ImportKind, ParseOptions, Span};
+
+#[test]
+fn test_entry_points() {
+  let source_text = r#"const worker = new Worker(new URL('./worker.js', import.meta.url), { type: 'module' });
+new SharedWorker('./shared.js', { name: 'shared' });
+navigator.serviceWorker.register('/sw.js', { type: 'module', scope: '/' });
+CSS.paintWorklet.addModule('./paint.js');
+context.audioWorklet.addModule(new URL('./processor.js', import.meta.url));
+importScripts('./a.js', "./b.js");
+new Worker(workerUrl);
+new Foo('./foo.js');
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let slice = |span: Span| &source_text[span.start as usize..span.end as usize];
+  let entry_points = result
+    .entry_points
+    .iter()
+    .map(|entry_point| {
+      (
+        entry_point.kind,
+        entry_point.n.as_deref(),
+        slice(entry_point.specifier_span),
+        entry_point.is_url,
+        entry_point.worker_type.as_deref(),
+      )
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    entry_points,
+    [
+      (
+        EntryPointKind::Worker,
+        Some("./worker.js"),
+        "./worker.js",
+        true,
+        Some("module")
+      ),
+      (
+        EntryPointKind::SharedWorker,
+        Some("./shared.js"),
+        "./shared.js",
+        false,
+        None
+      ),
+      (
+        EntryPointKind::ServiceWorker,
+        Some("/sw.js"),
+        "/sw.js",
+        false,
+        Some("module")
+      ),
+      (
+        EntryPointKind::PaintWorklet,
+        Some("./paint.js"),
+        "./paint.js",
+        false,
+        None
+      ),
+      (
+        EntryPointKind::AudioWorklet,
+        Some("./processor.js"),
+        "./processor.js",
+        true,
+        None
+      ),
+      (
+        EntryPointKind::ImportScripts,
+        Some("./a.js"),
+        "./a.js",
+        false,
+        None
+      ),
+      (
+        EntryPointKind::ImportScripts,
+        Some("./b.js"),
+        "./b.js",
+        false,
+        None
+      ),
+      (EntryPointKind::Worker, None, "workerUrl", false, None),
+    ]
+  );
+  assert_eq!(
+    slice(result.entry_points[0].span),
+    "new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })"
+  );
+  assert_eq!(
+    slice(result.entry_points[5].span),
+    r#"importScripts('./a.js', "./b.js")"#
+  );
+  // the `import.meta` of `new URL()` is still an import
+  assert_eq!(result.imports.len(), 2);
+}
+
+#[test]
+fn test_entry_point_urls() {
+  let source_text = r#"new Worker(new URL('./worker.js', import.meta.url));
+navigator.serviceWorker.register(new URL('./sw.js', import.meta.url));
+const logo = new URL('./logo.png', import.meta.url);
+"#;
+
+  let options = ParseOptions {
+    asset_urls: true,
+    ..Default::default()
+  };
+  let result = parse_with_options(source_text, "index.js", options).unwrap();
+
+  assert_eq!(
+    result
+      .entry_points
+      .iter()
+      .map(|entry_point| entry_point.n.as_deref())
+      .collect::<Vec<_>>(),
+    [Some("./worker.js"), Some("./sw.js")]
+  );
+  // only the logo is an asset URL, the `import.meta` of the entry points are plain imports
+  assert_eq!(
+    result
+      .imports
+      .iter()
+      .map(|import| (import.kind, import.n.as_deref()))
+      .collect::<Vec<_>>(),
+    [
+      (ImportKind::ImportMeta, None),
+      (ImportKind::ImportMeta, None),
+      (ImportKind::AssetUrl, Some("./logo.png")),
+    ]
+  );
+}
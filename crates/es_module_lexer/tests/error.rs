@@ -1,4 +1,4 @@
-use es_module_lexer::parse;
+use es_module_lexer::{parse, Severity};
 
 #[test]
 fn test_parse_errors() {
@@ -20,3 +20,53 @@ import /*comment!*/.meta.asdf;
 
   assert!(result.is_err());
 }
+
+#[test]
+fn test_diagnostics() {
+  let source_text = "import a from 'a'\nvar 😀 a number = 1";
+
+  let file_path = "index.js";
+  let errors = parse(source_text, file_path).unwrap_err();
+
+  assert_eq!(errors.len(), 1);
+
+  let error = &errors[0];
+
+  assert_eq!(error.severity, Severity::Error);
+  assert!(!error.message.is_empty());
+  assert_eq!(error.labels.len(), 1);
+
+  let label = &error.labels[0];
+
+  assert_eq!(label.start.offset, 22);
+  assert_eq!(label.start.char_offset, 22);
+  assert_eq!(label.start.line, 2);
+  assert_eq!(label.start.column, 4);
+  assert_eq!(label.end.offset, 26);
+  assert_eq!(label.end.char_offset, 23);
+  assert_eq!(label.end.line, 2);
+  assert_eq!(label.end.column, 5);
+}
+
+#[test]
+fn test_render() {
+  let source_text = "var a number = 1";
+
+  let file_path = "index.js";
+  let errors = parse(source_text, file_path).unwrap_err();
+
+  assert_eq!(
+    errors[0].to_string(),
+    "Expected a semicolon or an implicit semicolon after a statement, but found none"
+  );
+  assert_eq!(
+    errors[0].help.as_deref(),
+    Some("Try insert a semicolon here")
+  );
+
+  let rendered = errors[0].render(source_text);
+
+  assert!(rendered.contains("Expected a semicolon"));
+  assert!(rendered.contains("var a number = 1"));
+  assert!(rendered.contains("help: Try insert a semicolon here"));
+}
@@ -0,0 +1,131 @@
+use es_module_lexer::{parse_with_options, ParseOptions};
+
+#[test]
+fn test_error_recovery() {
+  let source_text = r#"import { a } from 'a';
+import { b } from 'b'
+var c number = 1;
+export const d = 1;
+export { a, b };
+"#;
+
+  let options = ParseOptions {
+    error_recovery: true,
+    ..Default::default()
+  };
+
+  let result = parse_with_options(source_text, "index.js", options).unwrap();
+
+  assert_eq!(result.errors.len(), 1);
+  assert_eq!(result.imports.len(), 2);
+  assert_eq!(result.imports[0].n.as_deref(), Some("a"));
+  assert!(!result.imports[0].has_error);
+  assert_eq!(result.imports[1].n.as_deref(), Some("b"));
+  assert!(!result.imports[1].has_error);
+  assert_eq!(result.exports.len(), 3);
+  assert!(result.exports.iter().all(|export| !export.has_error));
+}
+
+#[test]
+fn test_error_recovery_flags() {
+  let source_text = r#"import {
+  a,
+  b c
+} from 'a';
+export const d = ;
+export { a };
+"#;
+
+  let options = ParseOptions {
+    error_recovery: true,
+    ..Default::default()
+  };
+
+  let result = parse_with_options(source_text, "index.js", options).unwrap();
+
+  assert_eq!(result.errors.len(), 2);
+  assert_eq!(result.errors[0].labels[0].start.line, 3);
+  assert_eq!(result.errors[1].labels[0].start.line, 5);
+  assert_eq!(result.imports.len(), 1);
+  assert_eq!(result.imports[0].n.as_deref(), Some("a"));
+  assert!(result.imports[0].has_error);
+  assert_eq!(result.exports.len(), 1);
+  assert_eq!(result.exports[0].n.as_deref(), Some("a"));
+  assert!(!result.exports[0].has_error);
+}
+
+#[test]
+fn test_error_recovery_after_blanking() {
+  let source_text = r#"import { a } from 'a';
+var c number = 1;
+export class D { #e; #e; }
+export { a };
+"#;
+
+  let options = ParseOptions {
+    error_recovery: true,
+    ..Default::default()
+  };
+
+  let result = parse_with_options(source_text, "index.js", options).unwrap();
+
+  // the duplicate private name is only reported by the parse of the recovered source text
+  assert_eq!(result.errors.len(), 2);
+  assert_eq!(result.errors[0].labels[0].start.line, 2);
+  assert_eq!(result.errors[1].labels[0].start.line, 3);
+  assert_eq!(result.exports.len(), 2);
+  assert_eq!(result.exports[0].n.as_deref(), Some("D"));
+  assert!(result.exports[0].has_error);
+  assert!(!result.exports[1].has_error);
+}
+
+#[test]
+fn test_without_error_recovery() {
+  let source_text = r#"import { a } from 'a';
+var c number = 1;
+"#;
+
+  assert!(parse_with_options(source_text, "index.js", ParseOptions::default()).is_err());
+}
+
+#[test]
+fn test_error_recovery_incomplete() {
+  let options = ParseOptions {
+    error_recovery: true,
+    ..Default::default()
+  };
+
+  for source_text in [
+    "import",
+    "import {",
+    "import { a } from",
+    "import { a } from 'a",
+    "export {",
+    "export default",
+    "import(",
+    "import('a'",
+    "import.meta.",
+    "export * from",
+    "export const { a",
+  ] {
+    let result = parse_with_options(source_text, "index.js", options.clone()).unwrap();
+
+    assert!(!result.errors.is_empty(), "{source_text}");
+  }
+}
+
+#[test]
+fn test_error_recovery_offsets() {
+  let source_text = "var 😀 = ;\nexport { a };\n";
+
+  let options = ParseOptions {
+    error_recovery: true,
+    ..Default::default()
+  };
+
+  let result = parse_with_options(source_text, "index.js", options).unwrap();
+
+  assert_eq!(result.exports.len(), 1);
+  assert_eq!(result.exports[0].s, 19);
+  assert_eq!(result.exports[0].e, 20);
+}
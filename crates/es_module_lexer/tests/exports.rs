@@ -0,0 +1,468 @@
+use es_module_lexer::{parse, parse_with_options, DefaultExportKind, ExportKind, ParseOptions};
+
+#[test]
+fn test_export_declarators() {
+  let source_text = r#"
+export const a = 1, b = 2;
+export let c, d = 3;
+export var { e, f: g, h: { i } = {}, ...j } = obj, [k, , l = 1, ...m] = arr;
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+
+  let names = result
+    .exports
+    .iter()
+    .map(|export| export.n.as_deref().unwrap())
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    names,
+    ["a", "b", "c", "d", "e", "g", "i", "j", "k", "l", "m"]
+  );
+
+  for export in &result.exports {
+    assert_eq!(export.ln, export.n);
+    assert_eq!(
+      &source_text[export.s as usize..export.e as usize],
+      export.n.as_deref().unwrap()
+    );
+    assert_eq!((export.ls, export.le), (export.s as i32, export.e as i32));
+  }
+}
+
+#[test]
+fn test_default_exports() {
+  let cases = [
+    (
+      "export default function foo() {}",
+      DefaultExportKind::Function,
+      Some("foo"),
+    ),
+    (
+      "export default async function foo() {}",
+      DefaultExportKind::Function,
+      Some("foo"),
+    ),
+    (
+      "export default function () {}",
+      DefaultExportKind::AnonymousFunction,
+      None,
+    ),
+    (
+      "export default class Foo {}",
+      DefaultExportKind::Class,
+      Some("Foo"),
+    ),
+    (
+      "export default abstract class Foo {}",
+      DefaultExportKind::Class,
+      Some("Foo"),
+    ),
+    (
+      "export default class {}",
+      DefaultExportKind::AnonymousClass,
+      None,
+    ),
+    (
+      "export default interface Foo {}",
+      DefaultExportKind::Interface,
+      Some("Foo"),
+    ),
+    (
+      "export default foo;",
+      DefaultExportKind::Expression,
+      Some("foo"),
+    ),
+    (
+      "export default (foo);",
+      DefaultExportKind::Expression,
+      Some("foo"),
+    ),
+    (
+      "export default foo.bar;",
+      DefaultExportKind::Expression,
+      None,
+    ),
+    (
+      "export default (a, b) => a;",
+      DefaultExportKind::AnonymousFunction,
+      None,
+    ),
+    (
+      "export default function* () {}",
+      DefaultExportKind::AnonymousFunction,
+      None,
+    ),
+    (
+      "export default (function foo() {});",
+      DefaultExportKind::AnonymousFunction,
+      None,
+    ),
+    (
+      "export default (class {});",
+      DefaultExportKind::AnonymousClass,
+      None,
+    ),
+  ];
+
+  for (source_text, kind, local) in cases {
+    let result = parse(source_text, "index.ts").unwrap();
+    let export = &result.exports[0];
+
+    assert_eq!(export.n.as_deref(), Some("default"), "{source_text}");
+    assert_eq!(export.default_kind, Some(kind), "{source_text}");
+    assert_eq!(
+      export.local.as_ref().map(|local| local.name.as_ref()),
+      local,
+      "{source_text}"
+    );
+
+    if let Some(local) = &export.local {
+      assert_eq!(
+        &source_text[local.start as usize..local.end as usize],
+        local.name
+      );
+    }
+  }
+}
+
+#[test]
+fn test_default_export_ln() {
+  // like es-module-lexer, `ln` is only set for named function and class declarations
+  let result = parse("export default foo;", "index.js").unwrap();
+
+  assert_eq!(result.exports[0].ln, None);
+  assert_eq!(result.exports[0].ls, -1);
+
+  let result = parse("export default class Foo {}", "index.js").unwrap();
+
+  assert_eq!(result.exports[0].ln.as_deref(), Some("Foo"));
+  assert_eq!(
+    result.exports[0].default_kind,
+    Some(DefaultExportKind::Class)
+  );
+
+  let result = parse("export { foo as default };", "index.js").unwrap();
+
+  assert_eq!(result.exports[0].default_kind, None);
+}
+
+#[test]
+fn test_export_kinds() {
+  let source_text = r#"
+export const a = 1;
+export let b;
+export var c;
+export function d() {}
+export class E {}
+let f = 1;
+function g() {}
+import { h } from 'mod';
+export { f, g as 'g-g', h };
+export { i } from 'mod';
+export default f;
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+
+  let kinds = result
+    .exports
+    .iter()
+    .map(|export| (export.n.as_deref().unwrap(), export.kind, export.is_type))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    kinds,
+    [
+      ("a", Some(ExportKind::Const), false),
+      ("b", Some(ExportKind::Let), false),
+      ("c", Some(ExportKind::Var), false),
+      ("d", Some(ExportKind::Function), false),
+      ("E", Some(ExportKind::Class), false),
+      ("f", Some(ExportKind::Let), false),
+      ("g-g", Some(ExportKind::Function), false),
+      ("h", None, false),
+      ("i", None, false),
+      ("default", Some(ExportKind::Let), false),
+    ]
+  );
+}
+
+#[test]
+fn test_ts_export_kinds() {
+  let source_text = r#"
+export enum A {}
+export declare enum B {}
+export interface C {}
+export type D = string;
+export namespace E { export const e = 1; }
+export namespace F { export type f = string; }
+export declare function g(): void;
+export declare const h: string;
+export declare class I {}
+export import J = E.e;
+interface K {}
+class K {}
+type L = string;
+export { K, L };
+export default interface M {}
+"#;
+
+  let result = parse(source_text, "index.ts").unwrap();
+
+  let kinds = result
+    .exports
+    .iter()
+    .map(|export| (export.n.as_deref().unwrap(), export.kind, export.is_type))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    kinds,
+    [
+      ("A", Some(ExportKind::Enum), false),
+      ("B", Some(ExportKind::Enum), true),
+      ("C", Some(ExportKind::Interface), true),
+      ("D", Some(ExportKind::TypeAlias), true),
+      ("E", Some(ExportKind::Namespace), false),
+      ("F", Some(ExportKind::Namespace), true),
+      ("g", Some(ExportKind::Function), true),
+      ("h", Some(ExportKind::Const), true),
+      ("I", Some(ExportKind::Class), true),
+      ("J", Some(ExportKind::ImportEquals), false),
+      ("K", Some(ExportKind::Class), false),
+      ("L", Some(ExportKind::TypeAlias), true),
+      ("default", Some(ExportKind::Interface), true),
+    ]
+  );
+
+  let result = parse("export const a = 1;", "index.d.ts").unwrap();
+
+  assert_eq!(result.exports[0].kind, Some(ExportKind::Const));
+  assert!(result.exports[0].is_type);
+}
+
+#[test]
+fn test_export_origins() {
+  let source_text = r#"export { a, b as c } from './a';
+export * as ns from './ns';
+export { d, e as f, g, h as default };
+import d, { e } from './d';
+import * as g from './g';
+const h = 1;
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+
+  let origins = result
+    .exports
+    .iter()
+    .map(|export| {
+      let origin = export.origin.as_ref()?;
+
+      Some((
+        origin.specifier.as_ref(),
+        origin.imported.as_deref(),
+        origin.import_index,
+      ))
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    origins,
+    [
+      Some(("./a", Some("a"), 0)),
+      Some(("./a", Some("b"), 0)),
+      Some(("./ns", None, 1)),
+      Some(("./d", Some("default"), 2)),
+      Some(("./d", Some("e"), 2)),
+      Some(("./g", None, 3)),
+      None,
+    ]
+  );
+}
+
+#[test]
+fn test_mutable_exports() {
+  let source_text = r#"export let count = 0;
+export var a = 1, b = 2;
+export const c = {};
+let d = 1, e = 1, f = 1, g = 1;
+export { d, e, f, g, h as default };
+export function h() {}
+count++;
+[a] = [3];
+({ d } = {});
+c.x = 1;
+for (g of []) {}
+h = null;
+function shadow(a, { b }) {
+  a = 1;
+  b = 2;
+  {
+    let e = 3;
+    e++;
+  }
+  var f;
+  if (true) {
+    f = 4;
+  }
+  try {} catch (g) { g = 5; }
+  return () => { var count; count = 6; };
+}
+export default count;
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+
+  let mutables = result
+    .exports
+    .iter()
+    .map(|export| (export.n.as_deref().unwrap(), export.is_mutable))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    mutables,
+    [
+      ("count", true),
+      ("a", true),
+      ("b", false),
+      ("c", false),
+      ("d", true),
+      ("e", false),
+      ("f", false),
+      ("g", true),
+      ("default", true),
+      ("h", true),
+      ("default", false),
+    ]
+  );
+
+  let writes = result.exports[0]
+    .writes
+    .iter()
+    .map(|span| &source_text[span.start as usize..span.end as usize])
+    .collect::<Vec<_>>();
+
+  assert_eq!(writes, ["count"]);
+  assert_eq!(result.exports[0].writes[0].start, 160);
+}
+
+#[test]
+fn test_hoisted_var_exports() {
+  let source_text = r#"if (1) {
+  var b;
+}
+for (var i = 0; i < 1; i++) {}
+for (var key in {}) {}
+export { b, i, key };
+b = 3;
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let exports = result
+    .exports
+    .iter()
+    .map(|export| (export.n.as_deref(), export.kind, export.is_mutable))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    exports,
+    [
+      (Some("b"), Some(ExportKind::Var), true),
+      (Some("i"), Some(ExportKind::Var), true),
+      // declared, not written, by the `for...in`
+      (Some("key"), Some(ExportKind::Var), false),
+    ]
+  );
+}
+
+#[test]
+fn test_export_dependencies() {
+  let source_text = r#"import React from 'react';
+import { a, b } from './ab';
+import * as ns from './ns';
+import type { T } from './types';
+const helper = () => a;
+function unused() { return b; }
+export const x: T = helper();
+export function y(b) { return b + ns.c; }
+export function Z() { return <React.Fragment><Inner /></React.Fragment>; }
+function Inner() { return <div />; }
+export { a as reexported };
+export { d } from './d';
+export default helper() + b;
+"#;
+
+  let options = ParseOptions {
+    dependencies: true,
+    ..Default::default()
+  };
+  let result = parse_with_options(source_text, "index.tsx", options).unwrap();
+
+  let dependencies = result
+    .exports
+    .iter()
+    .map(|export| {
+      let dependencies = export.dependencies.as_ref()?;
+
+      Some((
+        dependencies
+          .imports
+          .iter()
+          .map(|x| x.as_ref())
+          .collect::<Vec<_>>(),
+        dependencies
+          .declarations
+          .iter()
+          .map(|x| x.as_ref())
+          .collect::<Vec<_>>(),
+      ))
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    dependencies,
+    [
+      Some((vec!["a"], vec!["helper"])),
+      Some((vec!["ns"], vec![])),
+      Some((vec!["React"], vec!["Inner"])),
+      None,
+      None,
+      Some((vec!["a", "b"], vec!["helper"])),
+    ]
+  );
+
+  let result = parse(source_text, "index.tsx").unwrap();
+
+  assert!(result
+    .exports
+    .iter()
+    .all(|export| export.dependencies.is_none()));
+}
+
+#[test]
+fn test_anonymous_default_export_dependencies() {
+  let options = || ParseOptions {
+    dependencies: true,
+    ..Default::default()
+  };
+
+  for declaration in [
+    "function () { return a + helper(); }",
+    "class { m() { return a + helper(); } }",
+    "() => a + helper()",
+  ] {
+    let source_text = format!(
+      "import {{ a }} from './a';
+const helper = () => 1;
+export default {declaration};
+"
+    );
+    let result = parse_with_options(&source_text, "index.js", options()).unwrap();
+    let dependencies = result.exports[0].dependencies.as_ref().unwrap();
+
+    assert_eq!(dependencies.imports, ["a"], "{declaration}");
+    assert_eq!(dependencies.declarations, ["helper"], "{declaration}");
+  }
+}
@@ -0,0 +1,144 @@
+use es_module_lexer::{
+  parse, parse_with_options, Binding, ImportKind, ImportType, ParseOptions, Span,
+};
+use std::borrow::Cow;
+
+#[test]
+fn test_import_kind() {
+  let source_text = r#"import json from './json.json' with { type: 'json' };
+export { a } from 'a';
+export * from 'b';
+import('c', { with: { type: 'json' } });
+import.meta.url;
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let imports = &result.imports;
+
+  assert_eq!(imports[0].kind, ImportKind::Static);
+  assert_eq!(imports[1].kind, ImportKind::ReExport);
+  assert_eq!(imports[2].kind, ImportKind::ExportStar);
+  assert_eq!(imports[3].kind, ImportKind::Dynamic { paren: 102 });
+  assert_eq!(imports[4].kind, ImportKind::ImportMeta);
+
+  assert_eq!(
+    imports.iter().map(|import| import.t).collect::<Vec<_>>(),
+    [
+      ImportType::Static,
+      ImportType::Static,
+      ImportType::Static,
+      ImportType::Dynamic,
+      ImportType::ImportMeta
+    ]
+  );
+  assert_eq!(
+    imports.iter().map(|import| import.d).collect::<Vec<_>>(),
+    [-1, -1, -1, 102, -2]
+  );
+}
+
+#[test]
+fn test_asset_urls() {
+  let source_text = r#"const logo = new URL('./logo.png', import.meta.url);
+const icon = new URL(`./icons/${name}.svg`, import.meta.url).href;
+new URL('./a.js', location.href);
+import.meta.url;
+"#;
+
+  let options = ParseOptions {
+    asset_urls: true,
+    ..Default::default()
+  };
+  let result = parse_with_options(source_text, "index.js", options).unwrap();
+  let imports = &result.imports;
+  let slice = |start: u32, end: u32| &source_text[start as usize..end as usize];
+
+  assert_eq!(imports.len(), 3);
+  assert_eq!(imports[0].kind, ImportKind::AssetUrl);
+  assert_eq!(imports[0].n.as_deref(), Some("./logo.png"));
+  assert_eq!(slice(imports[0].s, imports[0].e), "./logo.png");
+  assert_eq!(
+    slice(imports[0].ss, imports[0].se),
+    "new URL('./logo.png', import.meta.url)"
+  );
+  assert_eq!(imports[0].t, ImportType::ImportMeta);
+  assert_eq!(imports[0].d, -2);
+
+  assert_eq!(imports[1].kind, ImportKind::AssetUrl);
+  assert_eq!(imports[1].n, None);
+  assert_eq!(slice(imports[1].s, imports[1].e), "`./icons/${name}.svg`");
+  assert_eq!(
+    imports[1].pattern.as_ref().map(|x| x.glob.as_str()),
+    Some("./icons/*.svg")
+  );
+
+  assert_eq!(imports[2].kind, ImportKind::ImportMeta);
+
+  let result = parse(source_text, "index.js").unwrap();
+
+  assert!(result
+    .imports
+    .iter()
+    .all(|import| import.kind == ImportKind::ImportMeta));
+}
+
+#[test]
+fn test_attributes() {
+  let source_text = r#"import json from './json.json' with { type: 'json' };
+import('c', { with: { type: 'json' } });
+import 'd';
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let imports = &result.imports;
+
+  assert_eq!(
+    imports[0].attributes.as_ref().map(|x| x.span),
+    Some(Span { start: 36, end: 52 })
+  );
+  assert_eq!(&source_text[36..52], "{ type: 'json' }");
+  assert_eq!(imports[0].a, 36);
+
+  assert_eq!(
+    imports[1].attributes.as_ref().map(|x| x.span),
+    Some(Span { start: 66, end: 92 })
+  );
+  assert_eq!(&source_text[66..92], "{ with: { type: 'json' } }");
+  assert_eq!(imports[1].a, 66);
+
+  assert_eq!(imports[2].attributes, None);
+  assert_eq!(imports[2].a, -1);
+}
+
+#[test]
+fn test_export_local() {
+  let source_text = r#"const a = 1;
+export { a as b };
+export { c } from 'c';
+export default function foo() {}
+"#;
+
+  let result = parse(source_text, "index.js").unwrap();
+  let exports = &result.exports;
+
+  assert_eq!(
+    exports[0].local,
+    Some(Binding {
+      name: Cow::Borrowed("a"),
+      start: 22,
+      end: 23,
+      literal: None,
+    })
+  );
+  assert_eq!(exports[1].local, None);
+  assert_eq!(exports[1].ls, -1);
+  assert_eq!(
+    exports[2].local,
+    Some(Binding {
+      name: Cow::Borrowed("foo"),
+      start: 79,
+      end: 82,
+      literal: None,
+    })
+  );
+}
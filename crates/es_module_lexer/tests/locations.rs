@@ -0,0 +1,68 @@
+use es_module_lexer::{
+  parse, parse_with_options, LineColumn, Location, OffsetEncoding, ParseOptions,
+};
+
+fn loc(start: (u32, u32), end: (u32, u32)) -> Option<Location> {
+  Some(Location {
+    start: LineColumn {
+      line: start.0,
+      column: start.1,
+    },
+    end: LineColumn {
+      line: end.0,
+      column: end.1,
+    },
+  })
+}
+
+#[test]
+fn test_locations() {
+  let source_text = r#"import { a } from 'a';
+export { a as b };
+  import('😀' + c);
+"#;
+
+  let result = parse_with_options(
+    source_text,
+    "index.js",
+    ParseOptions {
+      locations: true,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+
+  assert_eq!(result.imports[0].loc, loc((1, 19), (1, 20)));
+  assert_eq!(result.imports[0].sloc, loc((1, 0), (1, 21)));
+  assert_eq!(result.exports[0].loc, loc((2, 14), (2, 15)));
+  assert_eq!(result.imports[1].loc, loc((3, 9), (3, 16)));
+  assert_eq!(result.imports[1].sloc, loc((3, 2), (3, 17)));
+}
+
+#[test]
+fn test_locations_utf16() {
+  let source_text = "// 😀\nimport '😀';";
+
+  let result = parse_with_options(
+    source_text,
+    "index.js",
+    ParseOptions {
+      locations: true,
+      offset_encoding: OffsetEncoding::Utf16,
+      ..Default::default()
+    },
+  )
+  .unwrap();
+
+  assert_eq!(result.imports[0].loc, loc((2, 8), (2, 10)));
+}
+
+#[test]
+fn test_no_locations() {
+  let source_text = "import 'a';";
+
+  let result = parse(source_text, "index.js").unwrap();
+
+  assert_eq!(result.imports[0].loc, None);
+  assert_eq!(result.imports[0].sloc, None);
+}
@@ -19,7 +19,7 @@ import /*comment!*/.meta.asdf;
   let file_path = "index.ts";
 
   let result = parse_multiple(&[ParseMultipleInput {
-    source_text: source_text,
+    source_text,
     file_path,
   }]);
 
@@ -0,0 +1,94 @@
+use es_module_lexer::{parse_with_options, OffsetEncoding, ParseOptions};
+
+const SOURCE_TEXT: &str = r#"// 😀 é
+import { a } from 'ä';
+export { a as b };
+"#;
+
+fn parse_with_encoding(offset_encoding: OffsetEncoding) -> es_module_lexer::ParseResult<'static> {
+  parse_with_options(
+    SOURCE_TEXT,
+    "index.js",
+    ParseOptions {
+      offset_encoding,
+      ..Default::default()
+    },
+  )
+  .unwrap()
+}
+
+#[test]
+fn test_utf8() {
+  let result = parse_with_encoding(OffsetEncoding::Utf8);
+
+  let import = &result.imports[0];
+
+  assert_eq!(&SOURCE_TEXT[import.s as usize..import.e as usize], "ä");
+  assert_eq!(
+    &SOURCE_TEXT[import.ss as usize..import.se as usize],
+    "import { a } from 'ä'"
+  );
+
+  let export = &result.exports[0];
+
+  assert_eq!(&SOURCE_TEXT[export.s as usize..export.e as usize], "b");
+  assert_eq!(&SOURCE_TEXT[export.ls as usize..export.le as usize], "a");
+}
+
+#[test]
+fn test_char() {
+  let result = parse_with_encoding(OffsetEncoding::Char);
+  let chars: Vec<char> = SOURCE_TEXT.chars().collect();
+  let slice = |s: u32, e: u32| chars[s as usize..e as usize].iter().collect::<String>();
+
+  let import = &result.imports[0];
+
+  assert_eq!(slice(import.s, import.e), "ä");
+  assert_eq!(slice(import.ss, import.se), "import { a } from 'ä'");
+
+  let export = &result.exports[0];
+
+  assert_eq!(slice(export.s, export.e), "b");
+  assert_eq!(slice(export.ls as u32, export.le as u32), "a");
+}
+
+#[test]
+fn test_utf16() {
+  let result = parse_with_encoding(OffsetEncoding::Utf16);
+  let code_units: Vec<u16> = SOURCE_TEXT.encode_utf16().collect();
+  let slice = |s: u32, e: u32| String::from_utf16(&code_units[s as usize..e as usize]).unwrap();
+
+  let import = &result.imports[0];
+
+  assert_eq!(import.s, 27);
+  assert_eq!(slice(import.s, import.e), "ä");
+  assert_eq!(slice(import.ss, import.se), "import { a } from 'ä'");
+
+  let export = &result.exports[0];
+
+  assert_eq!(slice(export.s, export.e), "b");
+  assert_eq!(slice(export.ls as u32, export.le as u32), "a");
+}
+
+#[test]
+fn test_end_of_source() {
+  let source_text = "// 😀\nimport('a')";
+
+  for (offset_encoding, se) in [
+    (OffsetEncoding::Utf8, 19),
+    (OffsetEncoding::Char, 16),
+    (OffsetEncoding::Utf16, 17),
+  ] {
+    let result = parse_with_options(
+      source_text,
+      "index.js",
+      ParseOptions {
+        offset_encoding,
+        ..Default::default()
+      },
+    )
+    .unwrap();
+
+    assert_eq!(result.imports[0].se, se);
+  }
+}
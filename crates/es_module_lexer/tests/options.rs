@@ -0,0 +1,245 @@
+use es_module_lexer::{
+  parse, parse_multiple, parse_multiple_with_options, parse_with_options, Language, ModuleKind,
+  ParseMultipleInput, ParseOptions,
+};
+
+#[test]
+fn test_language_override() {
+  let source_text = r#"
+import type { Foo } from 'mod'
+export const a: Foo = {};
+"#;
+
+  assert!(parse(source_text, "index.js").is_err());
+
+  let result = parse_with_options(
+    source_text,
+    "index.js",
+    ParseOptions {
+      language: Some(Language::TypeScript),
+      ..Default::default()
+    },
+  );
+
+  let result = result.unwrap();
+
+  assert_eq!(result.imports.len(), 1);
+  assert_eq!(result.exports.len(), 1);
+}
+
+#[test]
+fn test_jsx_override() {
+  let source_text = r#"
+import React from 'react'
+export const App = () => <div />;
+"#;
+
+  assert!(parse(source_text, "index.ts").is_err());
+
+  let result = parse_with_options(
+    source_text,
+    "index.ts",
+    ParseOptions {
+      jsx: Some(true),
+      ..Default::default()
+    },
+  );
+
+  assert!(result.is_ok());
+}
+
+#[test]
+fn test_jsx_of_language_override() {
+  let source_text = r#"
+import { a } from 'a'
+export const b = <string>a;
+"#;
+
+  // a `.js` file parsed as TypeScript has no JSX, so `<string>a` is a cast
+  let result = parse_with_options(
+    source_text,
+    "index.js",
+    ParseOptions {
+      language: Some(Language::TypeScript),
+      ..Default::default()
+    },
+  );
+
+  assert_eq!(result.unwrap().exports.len(), 1);
+
+  // the fallback language enables JSX for JavaScript, like a `.js` file
+  let result = parse_with_options(
+    "export const App = () => <div />;",
+    "\0virtual:foo",
+    ParseOptions {
+      fallback_language: Some(Language::JavaScript),
+      ..Default::default()
+    },
+  );
+
+  assert_eq!(result.unwrap().exports.len(), 1);
+
+  // `.tsx` keeps JSX when its language is set explicitly
+  let result = parse_with_options(
+    "export const App = () => <div />;",
+    "index.tsx",
+    ParseOptions {
+      language: Some(Language::TypeScript),
+      ..Default::default()
+    },
+  );
+
+  assert!(result.is_ok());
+}
+
+#[test]
+fn test_language_without_path() {
+  let source_text = r#"
+import { a } from 'a'
+export declare const b: string;
+"#;
+
+  let result = parse_with_options(
+    source_text,
+    "",
+    ParseOptions {
+      language: Some(Language::TypeScriptDefinition),
+      ..Default::default()
+    },
+  );
+
+  assert_eq!(result.unwrap().imports.len(), 1);
+}
+
+#[test]
+fn test_script_goal() {
+  let source_text = r#"
+var await = 1;
+"#;
+
+  assert!(parse(source_text, "index.js").is_err());
+
+  let result = parse_with_options(
+    source_text,
+    "index.js",
+    ParseOptions {
+      module_kind: Some(ModuleKind::Script),
+      ..Default::default()
+    },
+  );
+
+  assert!(result.is_ok());
+}
+
+#[test]
+fn test_multiple_with_options() {
+  let source_text = r#"
+import type { Foo } from 'mod'
+"#;
+
+  let result = parse_multiple_with_options(
+    &[
+      ParseMultipleInput {
+        source_text,
+        file_path: "a.js",
+      },
+      ParseMultipleInput {
+        source_text,
+        file_path: "b.js",
+      },
+    ],
+    ParseOptions {
+      language: Some(Language::TypeScript),
+      ..Default::default()
+    },
+  );
+
+  assert!(result["a.js"].is_ok());
+  assert!(result["b.js"].is_ok());
+}
+
+#[test]
+fn test_query_and_hash() {
+  let source_text = r#"
+import type { Foo } from 'mod'
+"#;
+
+  assert!(parse(source_text, "/src/foo.ts?raw").is_ok());
+  assert!(parse(source_text, "/src/foo.ts#hash").is_ok());
+  assert!(parse(source_text, "/src/foo.ts?import#hash").is_ok());
+}
+
+#[test]
+fn test_lang_query() {
+  let source_text = r#"
+import type { Foo } from 'mod'
+"#;
+
+  assert!(parse(
+    source_text,
+    "/src/App.vue?vue&type=script&setup=true&lang.ts"
+  )
+  .is_ok());
+  assert!(parse(source_text, "/src/App.svelte?lang=ts").is_ok());
+  assert!(parse(source_text, "/src/App.vue?vue&type=script&lang.js").is_err());
+}
+
+#[test]
+fn test_unknown_extension() {
+  let source_text = r#"
+import { a } from 'a'
+"#;
+
+  for file_path in [
+    "/src/App.vue",
+    "/src/App.svelte",
+    "Makefile",
+    "\0virtual:foo",
+    "",
+  ] {
+    let result = parse(source_text, file_path);
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err()[0].message.contains(file_path));
+  }
+}
+
+#[test]
+fn test_fallback_language() {
+  let source_text = r#"
+import type { Foo } from 'mod'
+"#;
+
+  let options = ParseOptions {
+    fallback_language: Some(Language::TypeScript),
+    ..Default::default()
+  };
+
+  let result = parse_with_options(source_text, "\0virtual:foo", options.clone());
+
+  assert_eq!(result.unwrap().imports.len(), 1);
+
+  // the fallback language doesn't override an inferred language
+  assert!(parse_with_options(source_text, "index.js", options).is_err());
+}
+
+#[test]
+fn test_multiple_unknown_extension() {
+  let source_text = r#"
+import { a } from 'a'
+"#;
+
+  let result = parse_multiple(&[
+    ParseMultipleInput {
+      source_text,
+      file_path: "a.js",
+    },
+    ParseMultipleInput {
+      source_text,
+      file_path: "b.vue",
+    },
+  ]);
+
+  assert!(result["a.js"].is_ok());
+  assert!(result["b.vue"].is_err());
+}
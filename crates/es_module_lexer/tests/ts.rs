@@ -1,4 +1,6 @@
-use es_module_lexer::parse;
+use es_module_lexer::{
+  parse, parse_with_options, ImportBindingKind, ImportKind, ImportType, ParseOptions,
+};
 
 #[test]
 fn test_parse() {
@@ -38,3 +40,198 @@ export type Key = 'a' | 'b' | 'c';
   assert!(!result.facade);
   assert!(result.has_module_syntax);
 }
+
+#[test]
+fn test_namespaces() {
+  let source_text = r#"
+export namespace N {
+  export const a = 1;
+}
+export module M.Inner {}
+export declare namespace D {}
+export declare const b: number;
+export declare function c(): void;
+declare module 'ambient' {
+  export const d: string;
+  import e = require('e');
+}
+"#;
+
+  let result = parse(source_text, "index.ts").unwrap();
+
+  let names = result
+    .exports
+    .iter()
+    .map(|export| export.n.as_deref().unwrap())
+    .collect::<Vec<_>>();
+
+  assert_eq!(names, ["N", "M", "D", "b", "c"]);
+  assert!(result.imports.is_empty());
+}
+
+#[test]
+fn test_import_equals() {
+  let source_text = r#"import fs = require('fs');
+export import path = require('path');
+export import A = B.C;
+import D = E.F;
+"#;
+
+  let result = parse(source_text, "index.ts").unwrap();
+
+  assert_eq!(result.imports.len(), 2);
+  assert_eq!(result.imports[0].n.as_deref(), Some("fs"));
+  assert_eq!(result.imports[0].kind, ImportKind::ImportEquals);
+  assert_eq!(result.imports[0].t, ImportType::Static);
+  assert_eq!(
+    &source_text[result.imports[0].s as usize..result.imports[0].e as usize],
+    "fs"
+  );
+  assert_eq!(
+    &source_text[result.imports[0].ss as usize..result.imports[0].se as usize],
+    "import fs = require('fs')"
+  );
+  assert_eq!(
+    result.imports[0].bindings[0].kind,
+    ImportBindingKind::Namespace
+  );
+  assert_eq!(result.imports[0].bindings[0].local.name, "fs");
+  assert_eq!(result.imports[1].n.as_deref(), Some("path"));
+
+  let names = result
+    .exports
+    .iter()
+    .map(|export| export.n.as_deref().unwrap())
+    .collect::<Vec<_>>();
+
+  assert_eq!(names, ["path", "A"]);
+  assert!(result.has_module_syntax);
+}
+
+#[test]
+fn test_export_assignment() {
+  let source_text = "function foo() {}\nexport = foo;\n";
+
+  let result = parse(source_text, "index.ts").unwrap();
+
+  assert_eq!(result.exports.len(), 1);
+
+  let export = &result.exports[0];
+
+  assert!(export.is_export_assignment);
+  assert_eq!(export.n, None);
+  assert_eq!(export.ln.as_deref(), Some("foo"));
+  assert_eq!((export.s, export.e), (27, 30));
+  assert_eq!((export.ls, export.le), (27, 30));
+
+  let result = parse("export = { a: 1 };", "index.ts").unwrap();
+
+  assert!(result.exports[0].is_export_assignment);
+  assert_eq!(result.exports[0].ln, None);
+  assert_eq!(result.exports[0].ls, -1);
+}
+
+#[test]
+fn test_type_only() {
+  let source_text = r#"import type { A } from 'a';
+import { type B, C } from 'b';
+import { type D } from 'd';
+export type { E } from 'e';
+export { type F } from 'f';
+export type * from 'g';
+export type * as H from 'h';
+import type I = require('i');
+import J from 'j';
+interface K {}
+const L = 1;
+export type { L };
+export { type K as M, L as N };
+"#;
+
+  let result = parse(source_text, "index.ts").unwrap();
+
+  let imports = result
+    .imports
+    .iter()
+    .map(|import| (import.n.as_deref().unwrap(), import.is_type))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    imports,
+    [
+      ("a", true),
+      ("b", false),
+      ("d", true),
+      ("e", true),
+      ("f", true),
+      ("g", true),
+      ("h", true),
+      ("i", true),
+      ("j", false),
+    ]
+  );
+  assert_eq!(
+    result.imports[1]
+      .bindings
+      .iter()
+      .map(|binding| binding.is_type)
+      .collect::<Vec<_>>(),
+    [true, false]
+  );
+  assert!(result.imports[0].bindings[0].is_type);
+
+  let exports = result
+    .exports
+    .iter()
+    .map(|export| (export.n.as_deref().unwrap(), export.is_type))
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    exports,
+    [
+      ("E", true),
+      ("F", true),
+      ("H", true),
+      ("L", true),
+      ("M", true),
+      ("N", false),
+    ]
+  );
+
+  let options = ParseOptions {
+    skip_type_only: true,
+    ..Default::default()
+  };
+  let result = parse_with_options(source_text, "index.ts", options).unwrap();
+
+  assert_eq!(
+    result
+      .imports
+      .iter()
+      .map(|import| import.n.as_deref().unwrap())
+      .collect::<Vec<_>>(),
+    ["b", "j"]
+  );
+  assert_eq!(
+    result
+      .exports
+      .iter()
+      .map(|export| export.n.as_deref().unwrap())
+      .collect::<Vec<_>>(),
+    ["N"]
+  );
+
+  // indexes of origins skip the dropped imports
+  let options = ParseOptions {
+    skip_type_only: true,
+    ..Default::default()
+  };
+  let result = parse_with_options(
+    "export type { A } from 'a';\nexport { B } from 'b';",
+    "index.ts",
+    options,
+  )
+  .unwrap();
+
+  assert_eq!(result.exports[0].origin.as_ref().unwrap().import_index, 0);
+}
@@ -3,14 +3,141 @@
 
 /* auto-generated by NAPI-RS */
 
+export interface LineColumn {
+  line: number
+  column: number
+}
+export interface Location {
+  start: LineColumn
+  end: LineColumn
+}
+export interface QuotedString {
+  /** source text between the quotes */
+  raw: string
+  /** decoded value */
+  value: string
+  quote: 'single' | 'double'
+  /** `true` if `raw` contains escape sequences */
+  hasEscapes?: boolean
+}
+export interface Binding {
+  name: string
+  start: number
+  end: number
+  /** the string literal of string names, e.g. `'a-b'` in `import { 'a-b' as c } from 'mod'` */
+  literal?: QuotedString
+}
+export interface BindingReference {
+  start: number
+  end: number
+  /** `true` if it is in a type, which is erased from the JavaScript emit */
+  isType?: boolean
+}
+export interface ImportBinding {
+  kind: 'default' | 'namespace' | 'named'
+  /** imported name of named bindings */
+  imported?: Binding
+  /** local name, or the exported name for re-exports */
+  local: Binding
+  /** `true` if it only imports a type */
+  isType?: boolean
+  /** references to the local name in the module, empty if it is unused */
+  references: Array<BindingReference>
+}
+export interface ExportOrigin {
+  /** specifier of the source module */
+  specifier: string
+  /** imported name, `'default'` for default imports, `undefined` for namespaces */
+  imported?: string
+  /** index of the import in `imports` */
+  importIndex: number
+}
+export interface ExportDependencies {
+  /** local names of the imported bindings, in the order of `imports` */
+  imports: Array<string>
+  /** names of the other top-level declarations, in source order */
+  declarations: Array<string>
+}
+export interface Span {
+  start: number
+  end: number
+}
+export interface ImportAttribute {
+  key: string
+  keySpan: Span
+  value: string
+  valueSpan: Span
+}
+export interface ImportAttributes {
+  span: Span
+  /** `undefined` for an options object of a dynamic import without a `with` or `assert` key */
+  keyword?: 'with' | 'assert'
+  entries: Array<ImportAttribute>
+}
+export interface DynamicImportPattern {
+  /** static parts, one more than `expressions` */
+  quasis: Array<string>
+  /** spans of the expressions between the static parts */
+  expressions: Array<Span>
+  /** the static parts joined with `*` */
+  glob: string
+}
+export interface ImportMetaGlobOptions {
+  span: Span
+  eager?: boolean
+  import?: string
+  /** starting with `?`, object queries and `as` are serialized */
+  query?: string
+  exhaustive?: boolean
+  base?: string
+}
+export interface ImportMetaGlob {
+  kind: 'glob' | 'globEager'
+  /** literal patterns, negated patterns start with `!` */
+  patterns: Array<string>
+  /** literal values of the options object */
+  options?: ImportMetaGlobOptions
+  /** span of the whole call */
+  span: Span
+}
 export interface Import {
   n?: string
+  /** `true` if `n` is folded from a computed specifier, if `foldConstants` is enabled */
+  isDerived?: boolean
+  /** `true` for `new URL('./logo.png', import.meta.url)`, if `assetUrls` is enabled */
+  isAssetUrl?: boolean
   s: number
   e: number
   ss: number
   se: number
   d: number
   a: number
+  /** import type, `1` for static imports, `2` for dynamic imports and `3` for `import.meta` */
+  t: number
+  /**
+   * the string literal of the specifier, if `literals` is enabled
+   *
+   * Its `raw` is `s..e`, except for dynamic imports, whose `s..e` includes the quotes.
+   */
+  literal?: QuotedString
+  /** the pattern of a template literal or string concatenation specifier of a dynamic import */
+  pattern?: DynamicImportPattern
+  /** the Vite `import.meta.glob()` call of an `import.meta` */
+  glob?: ImportMetaGlob
+  /** `true` if the whole statement only imports types */
+  isType?: boolean
+  /** `true` for `import 'mod'` and `import {} from 'mod'` */
+  isSideEffectOnly?: boolean
+  /** `true` if the statement overlaps a parse error, in error recovery mode */
+  hasError?: boolean
+  /** location of the name, if `locations` is enabled */
+  loc?: Location
+  /** location of the statement, if `locations` is enabled */
+  sloc?: Location
+  /** imported bindings, if `bindings` is enabled */
+  bindings?: Array<ImportBinding>
+  /** import attributes, if `attributes` is enabled */
+  attributes?: ImportAttributes
 }
 export interface Export {
   s: number
@@ -19,18 +146,114 @@ export interface Export {
   le: number
   n?: string
   ln?: string
+  /** the string literal of string names like `export { x as 'a-b' }`, if `literals` is enabled */
+  literal?: QuotedString
+  /** `true` for a TypeScript `export = foo`, which has no name */
+  isExportAssignment?: boolean
+  /** local binding, also for `export default foo`, if `exportDetails` is enabled */
+  local?: Binding
+  /** what an `export default` exports, if `exportDetails` is enabled */
+  defaultKind?: 'function' | 'class' | 'interface' | 'expression' | 'anonymousFunction' | 'anonymousClass'
+  /** what the local binding is declared as, if `exportDetails` is enabled */
+  kind?: 'var' | 'let' | 'const' | 'function' | 'class' | 'enum' | 'interface' | 'typeAlias' | 'namespace' | 'importEquals'
+  /** `true` if it only exports a type, if `exportDetails` is enabled, otherwise unset */
+  isType?: boolean
+  /** `true` if the local binding is written after its declaration, if `exportDetails` is enabled, otherwise unset */
+  isMutable?: boolean
+  /** spans of the identifiers written to the local binding, if `exportDetails` is enabled */
+  writes?: Array<Span>
+  /** the import it re-exports, if `exportDetails` is enabled */
+  origin?: ExportOrigin
+  /**
+   * what its declaration references, directly or through other top-level declarations,
+   * if `dependencies` is enabled
+   */
+  dependencies?: ExportDependencies
+  /** `true` if the export statement overlaps a parse error, in error recovery mode */
+  hasError?: boolean
+  /** location of the name, if `locations` is enabled */
+  loc?: Location
+}
+export interface ParseOptions {
+  language?: 'js' | 'ts' | 'dts'
+  fallbackLanguage?: 'js' | 'ts' | 'dts'
+  jsx?: boolean
+  moduleKind?: 'module' | 'script'
+  alwaysStrict?: boolean
+  errorRecovery?: boolean
+  /** the unit of the offsets, UTF-16 code units by default to match `String.prototype.slice` */
+  offsetEncoding?: 'utf8' | 'char' | 'utf16'
+  /** computes the line/column `loc` of every import and export */
+  locations?: boolean
+  /** adds the imported `bindings` of every import */
+  bindings?: boolean
+  /** adds the parsed `attributes` of every import */
+  attributes?: boolean
+  /** adds the `literal` of string specifiers and export names, with the raw text and quote style */
+  literals?: boolean
+  /** adds the `local` binding, `defaultKind`, `kind`, `isType`, `isMutable`, `writes` and `origin` of every export */
+  exportDetails?: boolean
+  /** drops the imports and exports that are types only, like the JavaScript emit of TypeScript */
+  skipTypeOnly?: boolean
+  /** adds the `dependencies` of every export, for tree shaking estimates */
+  dependencies?: boolean
+  /** folds dynamic import specifiers computed from strings and top-level `const` strings into `n` */
+  foldConstants?: boolean
+  /** reports `new URL('./logo.png', import.meta.url)` as an asset URL named `./logo.png` instead of an `import.meta`, except the URLs of entry points */
+  assetUrls?: boolean
+  /** adds the `entryPoints` of workers, service workers, worklets and `importScripts()` */
+  entryPoints?: boolean
+}
+export interface EntryPoint {
+  kind: 'worker' | 'sharedWorker' | 'serviceWorker' | 'paintWorklet' | 'audioWorklet' | 'importScripts'
+  /** the specifier, if it is a string literal or `new URL()` of a string literal and `import.meta.url` */
+  n?: string
+  /** span of the specifier excluding the quotes, or of the whole argument without `n` */
+  specifierSpan: Span
+  /** `true` for `new URL('./worker.js', import.meta.url)` */
+  isUrl?: boolean
+  /** the literal `type` option of workers and service workers, e.g. `'module'` */
+  workerType?: string
+  /** span of the whole call or `new` expression */
+  span: Span
 }
 export interface Output {
   imports: Array<Import>
   exports: Array<Export>
   facade: boolean
   hasModuleSyntax: boolean
+  /** scripts loaded as workers, service workers, worklets or by `importScripts()`, if `entryPoints` is enabled */
+  entryPoints?: Array<EntryPoint>
+  /** parse errors, in error recovery mode */
+  errors?: Array<Diagnostic>
+}
+export interface Position {
+  /** offset in the selected `offsetEncoding` */
+  offset: number
+  /** offset in Unicode scalar values */
+  charOffset: number
+  /** 1-based line */
+  line: number
+  /** 0-based column, in the selected `offsetEncoding` */
+  column: number
+}
+export interface Label {
+  label?: string
+  start: Position
+  end: Position
+}
+export interface Diagnostic {
+  filePath: string
+  message: string
+  severity: 'error' | 'warning' | 'advice'
+  labels: Array<Label>
+  help?: string
 }
-export function parse(sourceText: string, filePath: string): Output
-export function parseAsync(sourceText: string, filePath: string): Promise<Output>
+export function parse(sourceText: string, filePath: string, options?: ParseOptions | undefined | null): Output
+export function parseAsync(sourceText: string, filePath: string, options?: ParseOptions | undefined | null): Promise<Output>
 export interface ParseMultipleInput {
   sourceText: string
   filePath: string
 }
-export function parseMultiple(inputs: Array<ParseMultipleInput>): Record<string, Output>
-export function parseMultipleAsync(inputs: Array<ParseMultipleInput>): Promise<Record<string, Output>>
+export function parseMultiple(inputs: Array<ParseMultipleInput>, options?: ParseOptions | undefined | null): Record<string, Output>
+export function parseMultipleAsync(inputs: Array<ParseMultipleInput>, options?: ParseOptions | undefined | null): Promise<Record<string, Output>>